@@ -0,0 +1,67 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use std::{hint::black_box, vec::Vec};
+use vecmat::{
+    prelude::*,
+    transform::{Affine3, Linear, Rotation3, Shift},
+    Matrix, Vector,
+};
+
+const POINTS: usize = 1 << 16;
+
+fn points() -> Vec<Vector<f32, 3>> {
+    (0..POINTS)
+        .map(|i| Vector::from([i as f32, (i % 7) as f32, -((i % 13) as f32)]))
+        .collect()
+}
+
+/// Points are reset before each iteration, so non-isometric transformations don't make them overflow.
+fn bench<X: Transform<Vector<f32, 3>>>(c: &mut Criterion, name: &str, t: X) {
+    let points = points();
+    let mut group = c.benchmark_group(name);
+    group.bench_function("apply", |bn| {
+        bn.iter_batched(
+            || points.clone(),
+            |mut points| {
+                for pos in points.iter_mut() {
+                    *pos = black_box(&t).apply(*pos);
+                }
+                points
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("apply_batch", |bn| {
+        bn.iter_batched(
+            || points.clone(),
+            |mut points| {
+                black_box(&t).apply_batch(&mut points);
+                points
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+fn transforms(c: &mut Criterion) {
+    bench(
+        c,
+        "rotation3_f32",
+        Rotation3::new(Vector::from([1.0f32, 2.0, 3.0]).normalize(), 0.5),
+    );
+    bench(
+        c,
+        "affine3_f32",
+        Affine3::new(
+            Shift::from(Vector::from([1.0f32, -2.0, 3.0])),
+            Linear::from(Matrix::from([
+                [1.0f32, 0.5, 0.0],
+                [0.0, 1.0, 0.5],
+                [0.5, 0.0, 1.0],
+            ])),
+        ),
+    );
+}
+
+criterion_group!(benches, transforms);
+criterion_main!(benches);
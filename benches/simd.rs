@@ -0,0 +1,89 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+use vecmat::{prelude::*, transform::Rotation3, Matrix, Quaternion, Vector};
+
+fn vector(c: &mut Criterion) {
+    let a = Vector::from([1.0f32, 2.0, 3.0, 4.0]);
+    let b = Vector::from([0.5f32, -1.5, 2.5, -3.5]);
+    let mut group = c.benchmark_group("vector_f32x4");
+    group.bench_function("add/scalar", |bn| bn.iter(|| black_box(a) + black_box(b)));
+    group.bench_function("add/simd", |bn| {
+        bn.iter(|| black_box(a).simd_add(black_box(b)))
+    });
+    group.bench_function("dot/scalar", |bn| {
+        bn.iter(|| black_box(a).dot(black_box(b)))
+    });
+    group.bench_function("dot/simd", |bn| {
+        bn.iter(|| black_box(a).simd_dot(black_box(b)))
+    });
+    group.finish();
+
+    let a = Vector::from([1.0f64, 2.0]);
+    let b = Vector::from([0.5f64, -1.5]);
+    let mut group = c.benchmark_group("vector_f64x2");
+    group.bench_function("mul/scalar", |bn| bn.iter(|| black_box(a) * black_box(b)));
+    group.bench_function("mul/simd", |bn| {
+        bn.iter(|| black_box(a).simd_mul(black_box(b)))
+    });
+    group.finish();
+
+    let a = Vector::from([1.0f64, 2.0, 3.0, 4.0]);
+    let b = Vector::from([0.5f64, -1.5, 2.5, -3.5]);
+    let mut group = c.benchmark_group("vector_f64x4");
+    group.bench_function("mul/scalar", |bn| bn.iter(|| black_box(a) * black_box(b)));
+    group.bench_function("mul/simd", |bn| {
+        bn.iter(|| black_box(a).simd_mul(black_box(b)))
+    });
+    group.bench_function("dot/scalar", |bn| {
+        bn.iter(|| black_box(a).dot(black_box(b)))
+    });
+    group.bench_function("dot/simd", |bn| {
+        bn.iter(|| black_box(a).simd_dot(black_box(b)))
+    });
+    group.finish();
+}
+
+fn matrix(c: &mut Criterion) {
+    let a = Matrix::<_, 4, 4>::indices().map(|(i, j)| (i * 4 + j) as f32);
+    let b = a.transpose() - Matrix::one();
+    let v = Vector::from([1.0f32, -2.0, 3.0, -4.0]);
+    let mut group = c.benchmark_group("matrix4x4_f32");
+    group.bench_function("dot/scalar", |bn| {
+        bn.iter(|| black_box(a).dot(black_box(b)))
+    });
+    group.bench_function("dot/simd", |bn| {
+        bn.iter(|| black_box(a).simd_dot(black_box(b)))
+    });
+    group.bench_function("dot_vector/scalar", |bn| {
+        bn.iter(|| black_box(a).dot(black_box(v)))
+    });
+    group.bench_function("dot_vector/simd", |bn| {
+        bn.iter(|| black_box(a).simd_dot_vector(black_box(v)))
+    });
+    group.finish();
+}
+
+fn quaternion(c: &mut Criterion) {
+    let a = Quaternion::new(1.0f32, 2.0, 3.0, 4.0);
+    let b = Quaternion::new(0.5f32, -1.5, 2.5, -3.5);
+    let mut group = c.benchmark_group("quaternion_f32");
+    group.bench_function("mul/scalar", |bn| bn.iter(|| black_box(a) * black_box(b)));
+    group.bench_function("mul/simd", |bn| {
+        bn.iter(|| black_box(a).simd_mul(black_box(b)))
+    });
+    group.finish();
+
+    let r = Rotation3::new(Vector::from([1.0f32, 2.0, 3.0]).normalize(), 0.5);
+    let v = Vector::from([1.0f32, -2.0, 3.0]);
+    let mut group = c.benchmark_group("rotation3_f32");
+    group.bench_function("apply/scalar", |bn| {
+        bn.iter(|| black_box(r).apply(black_box(v)))
+    });
+    group.bench_function("apply/simd", |bn| {
+        bn.iter(|| black_box(r).simd_apply(black_box(v)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, vector, matrix, quaternion);
+criterion_main!(benches);
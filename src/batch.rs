@@ -0,0 +1,401 @@
+//! Structure-of-arrays batches for processing many values at once.
+//!
+//! [`Lanes`] is a scalar which holds `L` independent values and performs all operations lane-wise.
+//! Because it implements the same numeric traits as an ordinary scalar, it could be used inside
+//! vectors, matrices, complex numbers and transformations with existing operators,
+//! and the compiler is able to vectorize the lane-wise loops.
+
+use crate::{matrix::DetStrategy, Complex, Quaternion, Vector};
+use core::{
+    array,
+    ops::{
+        Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Rem, RemAssign, Sub,
+        SubAssign,
+    },
+};
+use num_traits::{Inv, Num, One, Zero};
+
+/// Number of lanes used by [`Transform::apply_batch`](crate::Transform::apply_batch).
+pub const BATCH_LANES: usize = 8;
+
+/// Scalar consisting of `L` independent lanes.
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Lanes<T, const L: usize> {
+    data: [T; L],
+}
+
+/// Batch of `L` vectors stored as structure of arrays.
+pub type VectorBatch<T, const N: usize, const L: usize> = Vector<Lanes<T, L>, N>;
+
+impl<T, const L: usize> Lanes<T, L> {
+    /// Create lanes from an array of values.
+    pub fn from_array(data: [T; L]) -> Self {
+        Self { data }
+    }
+    /// Convert lanes into an array of values.
+    pub fn into_array(self) -> [T; L] {
+        self.data
+    }
+    /// Reference to the array of lane values.
+    pub fn as_array(&self) -> &[T; L] {
+        &self.data
+    }
+    /// Mutable reference to the array of lane values.
+    pub fn as_mut_array(&mut self) -> &mut [T; L] {
+        &mut self.data
+    }
+    /// Map each lane.
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> Lanes<U, L> {
+        Lanes::from_array(self.data.map(f))
+    }
+}
+impl<T: Copy, const L: usize> Lanes<T, L> {
+    /// Create lanes filled with the same value.
+    pub fn splat(x: T) -> Self {
+        Self::from_array([x; L])
+    }
+    /// Combine lanes of two values.
+    pub fn zip_map<U: Copy, V, F: FnMut(T, U) -> V>(
+        self,
+        other: Lanes<U, L>,
+        mut f: F,
+    ) -> Lanes<V, L> {
+        Lanes::from_array(array::from_fn(|i| f(self.data[i], other.data[i])))
+    }
+}
+
+impl<T, const L: usize> From<[T; L]> for Lanes<T, L> {
+    fn from(data: [T; L]) -> Self {
+        Self::from_array(data)
+    }
+}
+impl<T, const L: usize> From<Lanes<T, L>> for [T; L] {
+    fn from(lanes: Lanes<T, L>) -> Self {
+        lanes.into_array()
+    }
+}
+
+impl<T, const L: usize> Index<usize> for Lanes<T, L> {
+    type Output = T;
+    fn index(&self, i: usize) -> &T {
+        &self.data[i]
+    }
+}
+impl<T, const L: usize> IndexMut<usize> for Lanes<T, L> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        &mut self.data[i]
+    }
+}
+
+impl<T: Copy + Default, const L: usize> Default for Lanes<T, L> {
+    fn default() -> Self {
+        Self::splat(T::default())
+    }
+}
+
+impl<T, const L: usize> Neg for Lanes<T, L>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+    fn neg(self) -> Self {
+        self.map(|x| -x)
+    }
+}
+
+macro_rules! impl_binary_op {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident) => {
+        impl<T, const L: usize> $Op for Lanes<T, L>
+        where
+            T: $Op<Output = T> + Copy,
+        {
+            type Output = Self;
+            fn $op(self, other: Self) -> Self {
+                self.zip_map(other, |x, y| x.$op(y))
+            }
+        }
+        impl<T, const L: usize> $Op<T> for Lanes<T, L>
+        where
+            T: $Op<Output = T> + Copy,
+        {
+            type Output = Self;
+            fn $op(self, other: T) -> Self {
+                self.map(|x| x.$op(other))
+            }
+        }
+        impl<T, const L: usize> $OpAssign for Lanes<T, L>
+        where
+            T: $OpAssign + Copy,
+        {
+            fn $op_assign(&mut self, other: Self) {
+                for (x, y) in self.data.iter_mut().zip(other.data) {
+                    x.$op_assign(y);
+                }
+            }
+        }
+        impl<T, const L: usize> $OpAssign<T> for Lanes<T, L>
+        where
+            T: $OpAssign + Copy,
+        {
+            fn $op_assign(&mut self, other: T) {
+                for x in self.data.iter_mut() {
+                    x.$op_assign(other);
+                }
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add, AddAssign, add_assign);
+impl_binary_op!(Sub, sub, SubAssign, sub_assign);
+impl_binary_op!(Mul, mul, MulAssign, mul_assign);
+impl_binary_op!(Div, div, DivAssign, div_assign);
+impl_binary_op!(Rem, rem, RemAssign, rem_assign);
+
+impl<T, const L: usize> Inv for Lanes<T, L>
+where
+    T: Inv<Output = T>,
+{
+    type Output = Self;
+    fn inv(self) -> Self {
+        self.map(|x| x.inv())
+    }
+}
+
+impl<T, const L: usize> Zero for Lanes<T, L>
+where
+    T: Zero + Copy,
+{
+    fn zero() -> Self {
+        Self::splat(T::zero())
+    }
+    /// All lanes are zero.
+    fn is_zero(&self) -> bool {
+        self.data.iter().all(|x| x.is_zero())
+    }
+}
+
+impl<T, const L: usize> One for Lanes<T, L>
+where
+    T: One + Copy,
+{
+    fn one() -> Self {
+        Self::splat(T::one())
+    }
+}
+
+impl<T, const L: usize> Num for Lanes<T, L>
+where
+    T: Num + Copy,
+{
+    type FromStrRadixErr = T::FromStrRadixErr;
+    /// Parsed value is put into all lanes.
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        T::from_str_radix(s, radix).map(Self::splat)
+    }
+}
+
+impl<T: DetStrategy, const L: usize> DetStrategy for Lanes<T, L> {}
+
+impl<T, const N: usize, const L: usize> VectorBatch<T, N, L>
+where
+    T: Copy,
+{
+    /// Create batch where all lanes contain the same vector.
+    pub fn splat_vector(vec: Vector<T, N>) -> Self {
+        vec.map(Lanes::splat)
+    }
+    /// Pack `L` vectors into a batch.
+    pub fn from_vectors(vecs: [Vector<T, N>; L]) -> Self {
+        Vector::indices().map(|i| Lanes::from_array(vecs.map(|v| v[i])))
+    }
+    /// Unpack batch into `L` vectors.
+    pub fn into_vectors(self) -> [Vector<T, N>; L] {
+        array::from_fn(|l| self.map(|x| x[l]))
+    }
+    /// Load first `L` vectors from the slice.
+    ///
+    /// If the slice is shorter than `L` then the remaining lanes are filled with its last vector.
+    /// Panics if the slice is empty.
+    pub fn load(vecs: &[Vector<T, N>]) -> Self {
+        let last = vecs.len() - 1;
+        Self::from_vectors(array::from_fn(|l| vecs[l.min(last)]))
+    }
+    /// Store first lanes of the batch into the slice of length not greater than `L`.
+    pub fn store(self, vecs: &mut [Vector<T, N>]) {
+        for (dst, src) in vecs.iter_mut().zip(self.into_vectors()) {
+            *dst = src;
+        }
+    }
+}
+
+/// Point that could be packed into a batch of [`BATCH_LANES`] points.
+pub trait Batch: Copy {
+    /// Batch of points stored as structure of arrays.
+    type Lanes;
+    /// Pack points into a batch.
+    fn pack(points: [Self; BATCH_LANES]) -> Self::Lanes;
+    /// Unpack batch into points.
+    fn unpack(lanes: Self::Lanes) -> [Self; BATCH_LANES];
+}
+
+impl<T: Copy, const N: usize> Batch for Vector<T, N> {
+    type Lanes = VectorBatch<T, N, BATCH_LANES>;
+    fn pack(points: [Self; BATCH_LANES]) -> Self::Lanes {
+        VectorBatch::from_vectors(points)
+    }
+    fn unpack(lanes: Self::Lanes) -> [Self; BATCH_LANES] {
+        lanes.into_vectors()
+    }
+}
+impl<T: Copy> Batch for Complex<T> {
+    type Lanes = Complex<Lanes<T, BATCH_LANES>>;
+    fn pack(points: [Self; BATCH_LANES]) -> Self::Lanes {
+        Complex::from_vector(VectorBatch::from_vectors(points.map(Complex::into_vector)))
+    }
+    fn unpack(lanes: Self::Lanes) -> [Self; BATCH_LANES] {
+        lanes.into_vector().into_vectors().map(Complex::from_vector)
+    }
+}
+impl<T: Copy> Batch for Quaternion<T> {
+    type Lanes = Quaternion<Lanes<T, BATCH_LANES>>;
+    fn pack(points: [Self; BATCH_LANES]) -> Self::Lanes {
+        Quaternion::from_vector(VectorBatch::from_vectors(
+            points.map(Quaternion::into_vector),
+        ))
+    }
+    fn unpack(lanes: Self::Lanes) -> [Self; BATCH_LANES] {
+        lanes
+            .into_vector()
+            .into_vectors()
+            .map(Quaternion::from_vector)
+    }
+}
+
+/// Batch of [`BATCH_LANES`] points of type `P`.
+pub type PointBatch<P> = <P as Batch>::Lanes;
+
+/// Apply function `f` to slice of points processing [`BATCH_LANES`] points at once.
+///
+/// If the slice length is not a multiple of `BATCH_LANES` then the last batch is padded with its last point.
+pub fn apply_batched<P, F>(points: &mut [P], mut f: F)
+where
+    P: Batch,
+    F: FnMut(P::Lanes) -> P::Lanes,
+{
+    let mut chunks = points.chunks_exact_mut(BATCH_LANES);
+    for chunk in &mut chunks {
+        let chunk: &mut [P; BATCH_LANES] = chunk.try_into().unwrap();
+        *chunk = P::unpack(f(P::pack(*chunk)));
+    }
+    let rem = chunks.into_remainder();
+    if let Some(&last) = rem.last() {
+        let res = P::unpack(f(P::pack(array::from_fn(|l| *rem.get(l).unwrap_or(&last)))));
+        rem.copy_from_slice(&res[..rem.len()]);
+    }
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::{
+        complex::Moebius,
+        distr::{Invertible, Normal, Uniform},
+        matrix::Matrix4x4,
+        traits::Dot,
+        transform::{
+            Affine3, Homogeneous3, Isometry3, Linear, Rotation2, Rotation3, Scale, Shift,
+            Similarity3,
+        },
+        Transform,
+    };
+    use approx::assert_abs_diff_eq;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 64;
+    // Number of points that is not a multiple of `BATCH_LANES`.
+    const POINTS: usize = 3 * BATCH_LANES + 5;
+
+    #[test]
+    fn pack() {
+        let vecs = [0, 1, 2, 3].map(|i| Vector::from([i, 10 + i, 20 + i]));
+        let batch = VectorBatch::<i32, 3, 4>::from_vectors(vecs);
+        assert_eq!(batch[1], Lanes::from([10, 11, 12, 13]));
+        assert_eq!(batch.into_vectors(), vecs);
+
+        let batch = VectorBatch::<i32, 3, 4>::load(&vecs[..2]);
+        assert_eq!(batch[2], Lanes::from([20, 21, 21, 21]));
+        let mut out = [Vector::fill(0); 3];
+        (batch + VectorBatch::splat_vector(Vector::fill(100))).store(&mut out);
+        assert_eq!(out[1], Vector::from([101, 111, 121]));
+    }
+
+    #[test]
+    fn operators() {
+        let a = Lanes::from([1.0, 2.0, 3.0, 4.0]);
+        let b = Lanes::splat(2.0);
+        assert_eq!(a * b - a, a);
+        assert_eq!((a / 2.0).inv(), Lanes::from([2.0, 1.0, 2.0 / 3.0, 0.5]));
+        let v = Vector::from([a, b]);
+        assert_eq!(v.dot(v), a * a + b * b);
+    }
+
+    fn check_batch<X: Transform<Vector<f64, N>>, const N: usize>(t: X, rng: &mut XorShiftRng)
+    where
+        Normal: Distribution<Vector<f64, N>>,
+    {
+        let points: [Vector<f64, N>; POINTS] = array::from_fn(|_| rng.sample(&Normal));
+        let mut batch = points;
+        t.apply_batch(&mut batch);
+        for (x, y) in points.into_iter().zip(batch) {
+            assert_abs_diff_eq!(t.apply(x), y, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn transforms() {
+        let mut rng = XorShiftRng::seed_from_u64(0xBEEF);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            check_batch::<Shift<f64, 3>, 3>(rng.sample(&Normal), &mut rng);
+            check_batch::<Linear<f64, 3>, 3>(rng.sample(&Normal), &mut rng);
+            check_batch::<Scale<f64>, 3>(rng.sample(&Normal), &mut rng);
+            check_batch::<Rotation2<f64>, 2>(rng.sample(&Uniform), &mut rng);
+            check_batch::<Rotation3<f64>, 3>(rng.sample(&Uniform), &mut rng);
+            check_batch::<Affine3<f64>, 3>(rng.sample(&Invertible), &mut rng);
+            check_batch::<Isometry3<f64>, 3>(rng.sample(&Normal), &mut rng);
+            check_batch::<Similarity3<f64>, 3>(rng.sample(&Normal), &mut rng);
+
+            let mut mat: Matrix4x4<f64> = rng.sample(&Normal);
+            mat[(3, 3)] = 10.0;
+            check_batch::<Homogeneous3<f64>, 3>(Homogeneous3::from_matrix(mat), &mut rng);
+        }
+    }
+
+    #[test]
+    fn batched_fn() {
+        let mut rng = XorShiftRng::seed_from_u64(0xBEF0);
+        let a: Affine3<f64> = rng.sample(&Invertible);
+        let points: [Vector<f64, 3>; POINTS] = array::from_fn(|_| rng.sample(&Normal));
+        let mut batch = points;
+        apply_batched(&mut batch, a.batched());
+        for (x, y) in points.into_iter().zip(batch) {
+            assert_abs_diff_eq!(a.apply(x), y, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn complex_points() {
+        let mut rng = XorShiftRng::seed_from_u64(0xBEF1);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let m: Moebius<Complex<f64>> = rng.sample(&Invertible);
+            let points: [Complex<f64>; POINTS] = array::from_fn(|_| rng.sample(&Normal));
+            let mut batch = points;
+            m.apply_batch(&mut batch);
+            for (x, y) in points.into_iter().zip(batch) {
+                assert_abs_diff_eq!(m.apply(x), y, epsilon = 1e-9);
+            }
+        }
+    }
+}
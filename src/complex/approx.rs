@@ -1,4 +1,6 @@
-use crate::complex::{Complex, Moebius, Quaternion};
+use crate::complex::{
+    CayleyDickson, Complex, Dual, DualQuaternion, Moebius, Quaternion, SplitComplex,
+};
 use approx::{abs_diff_eq, AbsDiffEq};
 
 impl<T> AbsDiffEq for Complex<T>
@@ -27,6 +29,61 @@ where
     }
 }
 
+impl<T, A> AbsDiffEq for CayleyDickson<T, A>
+where
+    A: AbsDiffEq<Epsilon = T>,
+    T: AbsDiffEq<Epsilon = T> + Copy,
+{
+    type Epsilon = T;
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        abs_diff_eq!(self.re_ref(), other.re_ref(), epsilon = epsilon)
+            && abs_diff_eq!(self.im_ref(), other.im_ref(), epsilon = epsilon)
+    }
+}
+
+impl<T> AbsDiffEq for Dual<T>
+where
+    T: AbsDiffEq<Epsilon = T> + Copy,
+{
+    type Epsilon = T;
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        abs_diff_eq!(self.into_vector(), other.into_vector(), epsilon = epsilon)
+    }
+}
+
+impl<T> AbsDiffEq for SplitComplex<T>
+where
+    T: AbsDiffEq<Epsilon = T> + Copy,
+{
+    type Epsilon = T;
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        abs_diff_eq!(self.into_vector(), other.into_vector(), epsilon = epsilon)
+    }
+}
+
+impl<T> AbsDiffEq for DualQuaternion<T>
+where
+    T: AbsDiffEq<Epsilon = T> + Copy,
+{
+    type Epsilon = T;
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        abs_diff_eq!(self.real(), other.real(), epsilon = epsilon)
+            && abs_diff_eq!(self.dual(), other.dual(), epsilon = epsilon)
+    }
+}
+
 impl<T> AbsDiffEq for Moebius<T>
 where
     T: AbsDiffEq<Epsilon = T> + Copy,
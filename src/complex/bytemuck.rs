@@ -0,0 +1,9 @@
+use crate::complex::{Complex, Quaternion};
+use bytemuck::{Pod, Zeroable};
+
+// Both types are `#[repr(transparent)]` over vectors, so they have no padding.
+unsafe impl<T: Zeroable> Zeroable for Complex<T> {}
+unsafe impl<T: Pod> Pod for Complex<T> {}
+
+unsafe impl<T: Zeroable> Zeroable for Quaternion<T> {}
+unsafe impl<T: Pod> Pod for Quaternion<T> {}
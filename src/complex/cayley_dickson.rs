@@ -0,0 +1,382 @@
+use crate::{
+    complex::{Complex, Quaternion},
+    traits::{Conj, Dot, NormL2, Normalize},
+};
+use core::{
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+use num_traits::{Float, Inv, One, Zero};
+
+/// Cayley–Dickson construction, the pair `(a, b)` of elements of algebra `A` over scalar `T`.
+///
+/// Multiplication is defined as `(a, b)·(c, d) = (a·c - d*·b, d·a + b·c*)` and conjugation as `(a, b)* = (a*, -b)`.
+///
+/// Each step of the construction doubles the dimension of the algebra:
+/// `CayleyDickson<T, T>` is compatible with [`Complex`], `CayleyDickson<T, Complex<T>>` is compatible with [`Quaternion`],
+/// then go [`Octonion`] and [`Sedenion`].
+pub struct CayleyDickson<T, A> {
+    re: A,
+    im: A,
+    phantom: PhantomData<T>,
+}
+
+/// Octonion, the Cayley–Dickson construction over quaternions.
+///
+/// Octonions are neither commutative nor associative, but they are alternative and form a division algebra.
+pub type Octonion<T> = CayleyDickson<T, Quaternion<T>>;
+
+/// Sedenion, the Cayley–Dickson construction over octonions.
+///
+/// Sedenions aren't alternative and have zero divisors, but they are still power-associative.
+pub type Sedenion<T> = CayleyDickson<T, Octonion<T>>;
+
+impl<T, A> CayleyDickson<T, A> {
+    pub fn new(re: A, im: A) -> Self {
+        Self {
+            re,
+            im,
+            phantom: PhantomData,
+        }
+    }
+    pub fn split(self) -> (A, A) {
+        (self.re, self.im)
+    }
+    pub fn re_ref(&self) -> &A {
+        &self.re
+    }
+    pub fn im_ref(&self) -> &A {
+        &self.im
+    }
+    pub fn re_mut(&mut self) -> &mut A {
+        &mut self.re
+    }
+    pub fn im_mut(&mut self) -> &mut A {
+        &mut self.im
+    }
+}
+
+impl<T, A> CayleyDickson<T, A>
+where
+    A: Copy,
+{
+    pub fn re(&self) -> A {
+        self.re
+    }
+    pub fn im(&self) -> A {
+        self.im
+    }
+}
+
+impl<T, A: Clone> Clone for CayleyDickson<T, A> {
+    fn clone(&self) -> Self {
+        Self::new(self.re.clone(), self.im.clone())
+    }
+}
+impl<T, A: Copy> Copy for CayleyDickson<T, A> {}
+impl<T, A: Default> Default for CayleyDickson<T, A> {
+    fn default() -> Self {
+        Self::new(A::default(), A::default())
+    }
+}
+impl<T, A: PartialEq> PartialEq for CayleyDickson<T, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.re == other.re && self.im == other.im
+    }
+}
+
+impl<T, A> From<(A, A)> for CayleyDickson<T, A> {
+    fn from((re, im): (A, A)) -> Self {
+        Self::new(re, im)
+    }
+}
+impl<T, A> From<CayleyDickson<T, A>> for (A, A) {
+    fn from(cd: CayleyDickson<T, A>) -> Self {
+        cd.split()
+    }
+}
+
+impl<T> From<Complex<T>> for CayleyDickson<T, T> {
+    fn from(comp: Complex<T>) -> Self {
+        let (re, im) = comp.into();
+        Self::new(re, im)
+    }
+}
+impl<T> From<CayleyDickson<T, T>> for Complex<T> {
+    fn from(cd: CayleyDickson<T, T>) -> Self {
+        Complex::new(cd.re, cd.im)
+    }
+}
+impl<T> From<Quaternion<T>> for CayleyDickson<T, Complex<T>> {
+    fn from(quat: Quaternion<T>) -> Self {
+        let (w, x, y, z) = quat.into();
+        Self::new(Complex::new(w, x), Complex::new(y, z))
+    }
+}
+impl<T> From<CayleyDickson<T, Complex<T>>> for Quaternion<T> {
+    fn from(cd: CayleyDickson<T, Complex<T>>) -> Self {
+        let ((w, x), (y, z)) = (cd.re.into(), cd.im.into());
+        Quaternion::new(w, x, y, z)
+    }
+}
+
+impl<T> Octonion<T> {
+    pub fn from_array([a, b, c, d, e, f, g, h]: [T; 8]) -> Self {
+        Self::new(Quaternion::new(a, b, c, d), Quaternion::new(e, f, g, h))
+    }
+    pub fn into_array(self) -> [T; 8] {
+        let ([a, b, c, d], [e, f, g, h]) = (self.re.into(), self.im.into());
+        [a, b, c, d, e, f, g, h]
+    }
+}
+impl<T> From<[T; 8]> for Octonion<T> {
+    fn from(arr: [T; 8]) -> Self {
+        Self::from_array(arr)
+    }
+}
+impl<T> From<Octonion<T>> for [T; 8] {
+    fn from(oct: Octonion<T>) -> Self {
+        oct.into_array()
+    }
+}
+
+impl<T, A> Neg for CayleyDickson<T, A>
+where
+    A: Neg<Output = A>,
+{
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl<T, A> CayleyDickson<T, A>
+where
+    A: Conj + Neg<Output = A>,
+{
+    pub fn conj(self) -> Self {
+        Self::new(self.re.conj(), -self.im)
+    }
+}
+impl<T, A> Conj for CayleyDickson<T, A>
+where
+    A: Conj + Neg<Output = A>,
+{
+    fn conj(self) -> Self {
+        CayleyDickson::conj(self)
+    }
+}
+
+impl<T, A> Add for CayleyDickson<T, A>
+where
+    A: Add<Output = A>,
+{
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self::new(self.re + other.re, self.im + other.im)
+    }
+}
+impl<T, A> Sub for CayleyDickson<T, A>
+where
+    A: Sub<Output = A>,
+{
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.re - other.re, self.im - other.im)
+    }
+}
+impl<T, A> AddAssign for CayleyDickson<T, A>
+where
+    Self: Add<Output = Self> + Copy,
+{
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+impl<T, A> SubAssign for CayleyDickson<T, A>
+where
+    Self: Sub<Output = Self> + Copy,
+{
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T, A> Zero for CayleyDickson<T, A>
+where
+    A: Zero,
+{
+    fn zero() -> Self {
+        Self::new(A::zero(), A::zero())
+    }
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+}
+
+impl<T, A> Mul for CayleyDickson<T, A>
+where
+    A: Add<Output = A> + Sub<Output = A> + Mul<Output = A> + Neg<Output = A> + Conj + Copy,
+{
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        let (a, b) = self.split();
+        let (c, d) = other.split();
+        Self::new(a * c - d.conj() * b, d * a + b * c.conj())
+    }
+}
+impl<T, A> Mul<T> for CayleyDickson<T, A>
+where
+    A: Mul<T, Output = A>,
+    T: Copy,
+{
+    type Output = Self;
+    fn mul(self, other: T) -> Self {
+        Self::new(self.re * other, self.im * other)
+    }
+}
+impl<T, A> Div<T> for CayleyDickson<T, A>
+where
+    A: Div<T, Output = A>,
+    T: Copy,
+{
+    type Output = Self;
+    fn div(self, other: T) -> Self {
+        Self::new(self.re / other, self.im / other)
+    }
+}
+impl<T, A> MulAssign for CayleyDickson<T, A>
+where
+    Self: Mul<Output = Self> + Copy,
+{
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+impl<T, A> MulAssign<T> for CayleyDickson<T, A>
+where
+    Self: Mul<T, Output = Self> + Copy,
+{
+    fn mul_assign(&mut self, other: T) {
+        *self = *self * other;
+    }
+}
+impl<T, A> DivAssign<T> for CayleyDickson<T, A>
+where
+    Self: Div<T, Output = Self> + Copy,
+{
+    fn div_assign(&mut self, other: T) {
+        *self = *self / other;
+    }
+}
+
+impl<T, A> One for CayleyDickson<T, A>
+where
+    A: Zero + One + Sub<Output = A> + Neg<Output = A> + Conj + Copy,
+{
+    fn one() -> Self {
+        Self::new(A::one(), A::zero())
+    }
+}
+
+impl<T, A> Dot for CayleyDickson<T, A>
+where
+    A: Dot<Output = T>,
+    T: Add<Output = T>,
+{
+    type Output = T;
+    fn dot(self, other: Self) -> T {
+        self.re.dot(other.re) + self.im.dot(other.im)
+    }
+}
+
+impl<T, A> CayleyDickson<T, A>
+where
+    A: Dot<Output = T> + Copy,
+    T: Add<Output = T>,
+{
+    pub fn norm_sqr(self) -> T {
+        self.dot(self)
+    }
+}
+impl<T, A> CayleyDickson<T, A>
+where
+    A: Dot<Output = T> + Copy,
+    T: Float,
+{
+    pub fn norm(self) -> T {
+        self.norm_sqr().sqrt()
+    }
+}
+impl<T, A> NormL2 for CayleyDickson<T, A>
+where
+    A: Dot<Output = T> + Copy,
+    T: Float,
+{
+    type Output = T;
+    fn norm_l2(self) -> T {
+        self.norm()
+    }
+    fn norm_l2_sqr(self) -> T {
+        self.norm_sqr()
+    }
+}
+
+impl<T, A> CayleyDickson<T, A>
+where
+    A: Dot<Output = T> + Div<T, Output = A> + Copy,
+    T: Float,
+{
+    pub fn normalize(self) -> Self {
+        self / self.norm()
+    }
+}
+impl<T, A> Normalize for CayleyDickson<T, A>
+where
+    A: Dot<Output = T> + Div<T, Output = A> + Copy,
+    T: Float,
+{
+    fn normalize(self) -> Self {
+        CayleyDickson::normalize(self)
+    }
+}
+
+impl<T, A> CayleyDickson<T, A>
+where
+    A: Dot<Output = T> + Div<T, Output = A> + Neg<Output = A> + Conj + Copy,
+    T: Add<Output = T> + Copy,
+{
+    pub fn inv(self) -> Self {
+        self.conj() / self.norm_sqr()
+    }
+}
+impl<T, A> Inv for CayleyDickson<T, A>
+where
+    A: Dot<Output = T> + Div<T, Output = A> + Neg<Output = A> + Conj + Copy,
+    T: Add<Output = T> + Copy,
+{
+    type Output = Self;
+    fn inv(self) -> Self {
+        CayleyDickson::inv(self)
+    }
+}
+
+/// Division `a / b` is defined as `a·b⁻¹`.
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T, A> Div for CayleyDickson<T, A>
+where
+    Self: Mul<Output = Self> + Inv<Output = Self>,
+{
+    type Output = Self;
+    fn div(self, other: Self) -> Self {
+        self * other.inv()
+    }
+}
+impl<T, A> DivAssign for CayleyDickson<T, A>
+where
+    Self: Div<Output = Self> + Copy,
+{
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
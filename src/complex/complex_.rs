@@ -1,6 +1,6 @@
 use crate::{
-    matrix::Matrix2x2,
-    traits::{Conj, Dot, NormL1, NormL2, Normalize},
+    matrix::{DetStrategy, Matrix2x2},
+    traits::{Conj, Dot, Epsilon, NormL1, NormL2, Normalize},
     vector::Vector2,
 };
 use core::{
@@ -13,6 +13,11 @@ use num_traits::{Float, Inv, Num, One, Zero};
 /// Complex number.
 #[repr(transparent)]
 #[derive(Clone, Copy, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Complex<T> {
     vec: Vector2<T>,
 }
@@ -346,6 +351,15 @@ where
         self.vec.norm_l1()
     }
 }
+impl<T> Epsilon for Complex<T>
+where
+    Self: NormL1<Output = T> + Copy,
+    T: Epsilon,
+{
+    fn is_epsilon(&self) -> bool {
+        self.norm_l1().is_epsilon()
+    }
+}
 impl<T: Float> NormL2 for Complex<T> {
     type Output = T;
     fn norm_l2(self) -> T {
@@ -436,6 +450,8 @@ impl<T: Neg<Output = T> + Num + Copy> Rem for Complex<T> {
     }
 }
 
+impl<T: Neg<Output = T> + Num + Copy> DetStrategy for Complex<T> {}
+
 impl<T: Neg<Output = T> + Num + Copy> Num for Complex<T> {
     type FromStrRadixErr = ParseComplexError<T::FromStrRadixErr>;
     fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
@@ -1,5 +1,5 @@
 use crate::{
-    complex::{Complex, Moebius, Quaternion},
+    complex::{CayleyDickson, Complex, Dual, DualQuaternion, Moebius, Quaternion, SplitComplex},
     distr::*,
     matrix::Matrix,
     vector::Vector,
@@ -35,6 +35,24 @@ where
     }
 }
 
+impl<T> Distribution<Dual<T>> for Normal
+where
+    Normal: Distribution<Vector<T, 2>>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Dual<T> {
+        rng.sample(self).into()
+    }
+}
+
+impl<T> Distribution<SplitComplex<T>> for Normal
+where
+    Normal: Distribution<Vector<T, 2>>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> SplitComplex<T> {
+        rng.sample(self).into()
+    }
+}
+
 impl<T> Distribution<Quaternion<T>> for Normal
 where
     Normal: Distribution<Vector<T, 4>>,
@@ -62,6 +80,24 @@ where
     }
 }
 
+impl<T, A> Distribution<CayleyDickson<T, A>> for Normal
+where
+    Normal: Distribution<A>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> CayleyDickson<T, A> {
+        CayleyDickson::new(rng.sample(self), rng.sample(self))
+    }
+}
+
+impl<T> Distribution<DualQuaternion<T>> for Normal
+where
+    Normal: Distribution<Quaternion<T>>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DualQuaternion<T> {
+        DualQuaternion::new(rng.sample(self), rng.sample(self))
+    }
+}
+
 impl<T: Neg<Output = T> + Num + Copy> Distribution<Moebius<T>> for Invertible
 where
     Invertible: Distribution<Matrix<T, 2, 2>>,
@@ -0,0 +1,828 @@
+use crate::{
+    matrix::{DetStrategy, Matrix},
+    traits::Conj,
+    vector::{Vector, Vector2},
+};
+use core::{
+    cmp::Ordering,
+    iter::{Product, Sum},
+    num::FpCategory,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+};
+use num_traits::{Float, Inv, Num, NumCast, One, ToPrimitive, Zero};
+
+/// Dual number `a + bε`, where `ε² = 0`.
+///
+/// Any function `f` extended to dual numbers satisfies `f(a + bε) = f(a) + b·f'(a)ε`,
+/// so dual number can be used as a scalar for forward-mode automatic differentiation.
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct Dual<T> {
+    vec: Vector2<T>,
+}
+
+impl<T> Dual<T> {
+    pub fn new(real: T, dual: T) -> Self {
+        Self {
+            vec: [real, dual].into(),
+        }
+    }
+    pub fn from_vector(vec: Vector2<T>) -> Self {
+        Self { vec }
+    }
+    pub fn from_array(arr: [T; 2]) -> Self {
+        Self { vec: arr.into() }
+    }
+    pub fn from_tuple(tup: (T, T)) -> Self {
+        Self { vec: tup.into() }
+    }
+    pub fn into_vector(self) -> Vector2<T> {
+        self.vec
+    }
+    pub fn into_array(self) -> [T; 2] {
+        self.vec.into()
+    }
+    pub fn into_tuple(self) -> (T, T) {
+        self.vec.into()
+    }
+}
+
+impl<T> From<Vector2<T>> for Dual<T> {
+    fn from(vec: Vector2<T>) -> Self {
+        Self::from_vector(vec)
+    }
+}
+impl<T> From<Dual<T>> for Vector2<T> {
+    fn from(dual: Dual<T>) -> Self {
+        dual.into_vector()
+    }
+}
+impl<T> From<[T; 2]> for Dual<T> {
+    fn from(arr: [T; 2]) -> Self {
+        Self::from_array(arr)
+    }
+}
+impl<T> From<Dual<T>> for [T; 2] {
+    fn from(dual: Dual<T>) -> Self {
+        dual.into_array()
+    }
+}
+impl<T> From<(T, T)> for Dual<T> {
+    fn from(tup: (T, T)) -> Self {
+        Self::from_tuple(tup)
+    }
+}
+impl<T> From<Dual<T>> for (T, T) {
+    fn from(dual: Dual<T>) -> Self {
+        dual.into_tuple()
+    }
+}
+
+impl<T: Copy> Dual<T> {
+    pub fn real(&self) -> T {
+        self.vec.x()
+    }
+    pub fn dual(&self) -> T {
+        self.vec.y()
+    }
+}
+
+impl<T> Dual<T> {
+    pub fn real_ref(&self) -> &T {
+        self.vec.x_ref()
+    }
+    pub fn dual_ref(&self) -> &T {
+        self.vec.y_ref()
+    }
+    pub fn real_mut(&mut self) -> &mut T {
+        self.vec.x_mut()
+    }
+    pub fn dual_mut(&mut self) -> &mut T {
+        self.vec.y_mut()
+    }
+}
+
+impl<T: Zero> Dual<T> {
+    /// Constant, i.e. the value with zero derivative.
+    pub fn constant(value: T) -> Self {
+        Self::new(value, T::zero())
+    }
+}
+impl<T: One> Dual<T> {
+    /// Independent variable, i.e. the value with unit derivative.
+    pub fn variable(value: T) -> Self {
+        Self::new(value, T::one())
+    }
+}
+impl<T: Zero + One> Dual<T> {
+    pub fn eps() -> Self {
+        Self::new(T::zero(), T::one())
+    }
+}
+
+impl<T> Neg for Dual<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+    fn neg(self) -> Self {
+        (-self.vec).into()
+    }
+}
+
+impl<T> Dual<T>
+where
+    T: Neg<Output = T>,
+{
+    pub fn conj(self) -> Self {
+        let (a, b) = self.into();
+        Self::new(a, -b)
+    }
+}
+
+impl<T> Conj for Dual<T>
+where
+    T: Neg<Output = T>,
+{
+    fn conj(self) -> Self {
+        Dual::conj(self)
+    }
+}
+
+impl<T> Add for Dual<T>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        (self.vec + other.vec).into()
+    }
+}
+impl<T> Add<T> for Dual<T>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+    fn add(self, other: T) -> Self {
+        let (a, b) = self.into();
+        Self::new(a + other, b)
+    }
+}
+impl<T> Sub for Dual<T>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        (self.vec - other.vec).into()
+    }
+}
+impl<T> Sub<T> for Dual<T>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+    fn sub(self, other: T) -> Self {
+        let (a, b) = self.into();
+        Self::new(a - other, b)
+    }
+}
+
+macro_rules! reverse_add_sub {
+    ($T:ident) => {
+        /// Workaround for reverse addition.
+        impl Add<Dual<$T>> for $T {
+            type Output = Dual<$T>;
+            fn add(self, other: Dual<$T>) -> Self::Output {
+                other + self
+            }
+        }
+        /// Workaround for reverse subtraction.
+        impl Sub<Dual<$T>> for $T {
+            type Output = Dual<$T>;
+            fn sub(self, other: Dual<$T>) -> Self::Output {
+                -other + self
+            }
+        }
+    };
+}
+
+reverse_add_sub!(f32);
+reverse_add_sub!(f64);
+
+impl<T> AddAssign for Dual<T>
+where
+    T: AddAssign,
+{
+    fn add_assign(&mut self, other: Self) {
+        self.vec += other.vec;
+    }
+}
+impl<T> AddAssign<T> for Dual<T>
+where
+    T: AddAssign,
+{
+    fn add_assign(&mut self, other: T) {
+        *self.real_mut() += other;
+    }
+}
+impl<T> SubAssign for Dual<T>
+where
+    T: SubAssign,
+{
+    fn sub_assign(&mut self, other: Self) {
+        self.vec -= other.vec;
+    }
+}
+impl<T> SubAssign<T> for Dual<T>
+where
+    T: SubAssign,
+{
+    fn sub_assign(&mut self, other: T) {
+        *self.real_mut() -= other;
+    }
+}
+
+impl<T> Zero for Dual<T>
+where
+    T: Zero,
+{
+    fn zero() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+    fn is_zero(&self) -> bool {
+        self.vec.is_zero()
+    }
+}
+
+impl<T> Mul for Dual<T>
+where
+    T: Add<Output = T> + Mul<Output = T> + Copy,
+{
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.real() * other.real(),
+            self.real() * other.dual() + self.dual() * other.real(),
+        )
+    }
+}
+impl<T> Mul<T> for Dual<T>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+    fn mul(self, other: T) -> Self {
+        (self.vec * other).into()
+    }
+}
+
+impl<T> MulAssign for Dual<T>
+where
+    Self: Mul<Output = Self> + Copy,
+{
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+impl<T> MulAssign<T> for Dual<T>
+where
+    Self: Mul<T, Output = Self> + Copy,
+{
+    fn mul_assign(&mut self, other: T) {
+        *self = *self * other;
+    }
+}
+
+impl<T> One for Dual<T>
+where
+    T: Zero + One + Copy,
+{
+    fn one() -> Self {
+        Self::new(T::one(), T::zero())
+    }
+}
+
+impl<T> Dual<T>
+where
+    T: Mul<Output = T> + Copy,
+{
+    /// Square norm, `z·z* = a²`.
+    pub fn norm_sqr(self) -> T {
+        self.real() * self.real()
+    }
+}
+
+impl<T> Div<T> for Dual<T>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Self;
+    fn div(self, other: T) -> Self {
+        (self.vec / other).into()
+    }
+}
+
+impl<T> Dual<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    /// Inverse dual number, exists only if the real part is non-zero.
+    pub fn inv(self) -> Self {
+        let inv = T::one() / self.real();
+        Self::new(inv, -self.dual() * inv * inv)
+    }
+}
+impl<T> Inv for Dual<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    type Output = Self;
+    fn inv(self) -> Self {
+        Dual::inv(self)
+    }
+}
+
+impl<T> Div for Dual<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    type Output = Self;
+    fn div(self, other: Self) -> Self {
+        let (a, b) = self.into();
+        let (c, d) = other.into();
+        Self::new(a / c, (b * c - a * d) / (c * c))
+    }
+}
+
+impl<T> DivAssign for Dual<T>
+where
+    Self: Div<Output = Self> + Copy,
+{
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+impl<T> DivAssign<T> for Dual<T>
+where
+    Self: Div<T, Output = Self> + Copy,
+{
+    fn div_assign(&mut self, other: T) {
+        *self = *self / other;
+    }
+}
+
+/// Remainder `a - b·trunc(a / b)`, where the quotient is treated as locally constant.
+impl<T: Neg<Output = T> + Num + Copy> Rem for Dual<T> {
+    type Output = Self;
+    fn rem(self, other: Self) -> Self {
+        let (a, b) = self.into();
+        let (c, d) = other.into();
+        let r = a % c;
+        Self::new(r, b - d * ((a - r) / c))
+    }
+}
+impl<T> RemAssign for Dual<T>
+where
+    Self: Rem<Output = Self> + Copy,
+{
+    fn rem_assign(&mut self, other: Self) {
+        *self = *self % other;
+    }
+}
+
+impl<T: Neg<Output = T> + Num + Copy> DetStrategy for Dual<T> {}
+
+/// Only the real part is parsed, the dual part of the result is zero.
+impl<T: Neg<Output = T> + Num + Copy> Num for Dual<T> {
+    type FromStrRadixErr = T::FromStrRadixErr;
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        T::from_str_radix(s, radix).map(Self::constant)
+    }
+}
+
+macro_rules! reverse_mul_div {
+    ($T:ident) => {
+        /// Workaround for reverse multiplication.
+        impl Mul<Dual<$T>> for $T {
+            type Output = Dual<$T>;
+            fn mul(self, other: Dual<$T>) -> Self::Output {
+                other * self
+            }
+        }
+        /// Workaround for reverse division.
+        impl Div<Dual<$T>> for $T {
+            type Output = Dual<$T>;
+            fn div(self, other: Dual<$T>) -> Self::Output {
+                Dual::constant(self) / other
+            }
+        }
+    };
+}
+
+reverse_mul_div!(f32);
+reverse_mul_div!(f64);
+
+/// Dual numbers are compared by their real parts.
+///
+/// Numbers with equal real parts but different dual parts are incomparable,
+/// so that the ordering is consistent with equality.
+impl<T: PartialOrd> PartialOrd for Dual<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.real_ref().partial_cmp(other.real_ref()) {
+            Some(Ordering::Equal) if self.dual_ref() != other.dual_ref() => None,
+            ord => ord,
+        }
+    }
+}
+
+impl<T: Num + Copy> Dual<T> {
+    pub fn powu(&self, exp: u32) -> Self {
+        let (mut base, mut exp, mut acc) = (*self, exp, Self::one());
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc *= base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        acc
+    }
+}
+
+impl<T: Neg<Output = T> + Num + Copy> Dual<T> {
+    pub fn powi(&self, exp: i32) -> Self {
+        if exp < 0 {
+            self.powu(exp.unsigned_abs()).inv()
+        } else {
+            self.powu(exp as u32)
+        }
+    }
+}
+
+impl<T: Float> Dual<T> {
+    /// Apply function `f` with the derivative `df` to the dual number.
+    fn chain_rule<F: FnOnce(T) -> T, G: FnOnce(T) -> T>(self, f: F, df: G) -> Self {
+        let (a, b) = self.into();
+        Self::new(f(a), b * df(a))
+    }
+
+    pub fn exp(self) -> Self {
+        let e = self.real().exp();
+        Self::new(e, self.dual() * e)
+    }
+    pub fn ln(self) -> Self {
+        self.chain_rule(T::ln, T::recip)
+    }
+    pub fn sqrt(self) -> Self {
+        let s = self.real().sqrt();
+        Self::new(s, self.dual() / (s + s))
+    }
+    pub fn cbrt(self) -> Self {
+        let c = self.real().cbrt();
+        Self::new(c, self.dual() / (T::from(3).unwrap() * c * c))
+    }
+    pub fn powf(self, exp: T) -> Self {
+        if exp.is_zero() {
+            return Self::one();
+        }
+        let (a, b) = self.into();
+        Self::new(a.powf(exp), b * exp * a.powf(exp - T::one()))
+    }
+    pub fn log(self, base: T) -> Self {
+        self.ln() / base.ln()
+    }
+    pub fn powd(self, exp: Self) -> Self {
+        let (a, b) = self.into();
+        let (c, d) = exp.into();
+        let p = a.powf(c);
+        let mut du = if c.is_zero() {
+            T::zero()
+        } else {
+            b * c * a.powf(c - T::one())
+        };
+        if !d.is_zero() {
+            du = du + d * p * a.ln();
+        }
+        Self::new(p, du)
+    }
+    pub fn expf(self, base: T) -> Self {
+        (self * base.ln()).exp()
+    }
+    pub fn sin(self) -> Self {
+        self.chain_rule(T::sin, T::cos)
+    }
+    pub fn cos(self) -> Self {
+        self.chain_rule(T::cos, |a| -a.sin())
+    }
+    pub fn tan(self) -> Self {
+        let t = self.real().tan();
+        Self::new(t, self.dual() * (T::one() + t * t))
+    }
+    pub fn asin(self) -> Self {
+        self.chain_rule(T::asin, |a| (T::one() - a * a).sqrt().recip())
+    }
+    pub fn acos(self) -> Self {
+        self.chain_rule(T::acos, |a| -(T::one() - a * a).sqrt().recip())
+    }
+    pub fn atan(self) -> Self {
+        self.chain_rule(T::atan, |a| (T::one() + a * a).recip())
+    }
+    pub fn sinh(self) -> Self {
+        self.chain_rule(T::sinh, T::cosh)
+    }
+    pub fn cosh(self) -> Self {
+        self.chain_rule(T::cosh, T::sinh)
+    }
+    pub fn tanh(self) -> Self {
+        let t = self.real().tanh();
+        Self::new(t, self.dual() * (T::one() - t * t))
+    }
+    pub fn asinh(self) -> Self {
+        self.chain_rule(T::asinh, |a| (a * a + T::one()).sqrt().recip())
+    }
+    pub fn acosh(self) -> Self {
+        self.chain_rule(T::acosh, |a| (a * a - T::one()).sqrt().recip())
+    }
+    pub fn atanh(self) -> Self {
+        self.chain_rule(T::atanh, |a| (T::one() - a * a).recip())
+    }
+    pub fn finv(self) -> Self {
+        self.chain_rule(T::recip, |a| -(a * a).recip())
+    }
+    pub fn fdiv(self, other: Self) -> Self {
+        self * other.finv()
+    }
+}
+
+impl<T> Sum for Dual<T>
+where
+    Self: Zero + Add,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |a, x| a + x)
+    }
+}
+
+impl<T> Product for Dual<T>
+where
+    Self: One + Mul,
+{
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |a, x| a * x)
+    }
+}
+
+/// Conversion to primitives takes the real part only.
+impl<T: ToPrimitive> ToPrimitive for Dual<T> {
+    fn to_i64(&self) -> Option<i64> {
+        self.real_ref().to_i64()
+    }
+    fn to_u64(&self) -> Option<u64> {
+        self.real_ref().to_u64()
+    }
+    fn to_i128(&self) -> Option<i128> {
+        self.real_ref().to_i128()
+    }
+    fn to_u128(&self) -> Option<u128> {
+        self.real_ref().to_u128()
+    }
+    fn to_f32(&self) -> Option<f32> {
+        self.real_ref().to_f32()
+    }
+    fn to_f64(&self) -> Option<f64> {
+        self.real_ref().to_f64()
+    }
+}
+
+/// Conversion from primitives produces constants.
+impl<T: NumCast + Zero> NumCast for Dual<T> {
+    fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+        T::from(n).map(Self::constant)
+    }
+}
+
+/// Dual numbers as a scalar type for forward-mode automatic differentiation.
+///
+/// Predicates, comparisons and rounding functions use the real part only.
+impl<T: Float> Float for Dual<T> {
+    fn nan() -> Self {
+        Self::constant(T::nan())
+    }
+    fn infinity() -> Self {
+        Self::constant(T::infinity())
+    }
+    fn neg_infinity() -> Self {
+        Self::constant(T::neg_infinity())
+    }
+    fn neg_zero() -> Self {
+        Self::constant(T::neg_zero())
+    }
+    fn min_value() -> Self {
+        Self::constant(T::min_value())
+    }
+    fn min_positive_value() -> Self {
+        Self::constant(T::min_positive_value())
+    }
+    fn epsilon() -> Self {
+        Self::constant(T::epsilon())
+    }
+    fn max_value() -> Self {
+        Self::constant(T::max_value())
+    }
+    fn is_nan(self) -> bool {
+        self.real().is_nan() || self.dual().is_nan()
+    }
+    fn is_infinite(self) -> bool {
+        self.real().is_infinite()
+    }
+    fn is_finite(self) -> bool {
+        self.real().is_finite()
+    }
+    fn is_normal(self) -> bool {
+        self.real().is_normal()
+    }
+    fn classify(self) -> FpCategory {
+        self.real().classify()
+    }
+    fn floor(self) -> Self {
+        Self::constant(self.real().floor())
+    }
+    fn ceil(self) -> Self {
+        Self::constant(self.real().ceil())
+    }
+    fn round(self) -> Self {
+        Self::constant(self.real().round())
+    }
+    fn trunc(self) -> Self {
+        Self::constant(self.real().trunc())
+    }
+    fn fract(self) -> Self {
+        Self::new(self.real().fract(), self.dual())
+    }
+    fn abs(self) -> Self {
+        if self.real().is_sign_negative() {
+            -self
+        } else {
+            self
+        }
+    }
+    fn signum(self) -> Self {
+        Self::constant(self.real().signum())
+    }
+    fn is_sign_positive(self) -> bool {
+        self.real().is_sign_positive()
+    }
+    fn is_sign_negative(self) -> bool {
+        self.real().is_sign_negative()
+    }
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+    fn recip(self) -> Self {
+        self.finv()
+    }
+    fn powi(self, n: i32) -> Self {
+        Dual::powi(&self, n)
+    }
+    fn powf(self, n: Self) -> Self {
+        self.powd(n)
+    }
+    fn sqrt(self) -> Self {
+        Dual::sqrt(self)
+    }
+    fn exp(self) -> Self {
+        Dual::exp(self)
+    }
+    fn exp2(self) -> Self {
+        self.expf(T::one() + T::one())
+    }
+    fn ln(self) -> Self {
+        Dual::ln(self)
+    }
+    fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+    fn log2(self) -> Self {
+        Dual::log(self, T::one() + T::one())
+    }
+    fn log10(self) -> Self {
+        Dual::log(self, T::from(10).unwrap())
+    }
+    fn max(self, other: Self) -> Self {
+        if self.real().is_nan() || self < other {
+            other
+        } else {
+            self
+        }
+    }
+    fn min(self, other: Self) -> Self {
+        if self.real().is_nan() || self > other {
+            other
+        } else {
+            self
+        }
+    }
+    fn abs_sub(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            Self::zero()
+        }
+    }
+    fn cbrt(self) -> Self {
+        Dual::cbrt(self)
+    }
+    fn hypot(self, other: Self) -> Self {
+        let h = self.real().hypot(other.real());
+        Self::new(
+            h,
+            (self.real() * self.dual() + other.real() * other.dual()) / h,
+        )
+    }
+    fn sin(self) -> Self {
+        Dual::sin(self)
+    }
+    fn cos(self) -> Self {
+        Dual::cos(self)
+    }
+    fn tan(self) -> Self {
+        Dual::tan(self)
+    }
+    fn asin(self) -> Self {
+        Dual::asin(self)
+    }
+    fn acos(self) -> Self {
+        Dual::acos(self)
+    }
+    fn atan(self) -> Self {
+        Dual::atan(self)
+    }
+    fn atan2(self, other: Self) -> Self {
+        let (y, dy) = self.into();
+        let (x, dx) = other.into();
+        Self::new(y.atan2(x), (x * dy - y * dx) / (x * x + y * y))
+    }
+    fn sin_cos(self) -> (Self, Self) {
+        (Dual::sin(self), Dual::cos(self))
+    }
+    fn exp_m1(self) -> Self {
+        self.chain_rule(T::exp_m1, T::exp)
+    }
+    fn ln_1p(self) -> Self {
+        self.chain_rule(T::ln_1p, |a| (T::one() + a).recip())
+    }
+    fn sinh(self) -> Self {
+        Dual::sinh(self)
+    }
+    fn cosh(self) -> Self {
+        Dual::cosh(self)
+    }
+    fn tanh(self) -> Self {
+        Dual::tanh(self)
+    }
+    fn asinh(self) -> Self {
+        Dual::asinh(self)
+    }
+    fn acosh(self) -> Self {
+        Dual::acosh(self)
+    }
+    fn atanh(self) -> Self {
+        Dual::atanh(self)
+    }
+    fn integer_decode(self) -> (u64, i16, i8) {
+        self.real().integer_decode()
+    }
+}
+
+impl<T> Dual<T>
+where
+    T: Zero + One + Copy,
+{
+    /// Directional derivative `J(pos)·dir` of the function `f` computed in a single forward pass.
+    pub fn deriv<F, const M: usize, const N: usize>(
+        f: F,
+        pos: Vector<T, N>,
+        dir: Vector<T, N>,
+    ) -> Vector<T, M>
+    where
+        F: FnOnce(Vector<Self, N>) -> Vector<Self, M>,
+    {
+        f(pos.zip(dir).map(|(p, d)| Self::new(p, d))).map(|x| x.dual())
+    }
+
+    /// Jacobian matrix of the function `f` at `pos`, computed column by column.
+    pub fn jacobian<F, const M: usize, const N: usize>(
+        mut f: F,
+        pos: Vector<T, N>,
+    ) -> Matrix<T, M, N>
+    where
+        F: FnMut(Vector<Self, N>) -> Vector<Self, M>,
+    {
+        let cols = Vector::<usize, N>::indices().map(|j| {
+            let dir = Vector::indices().map(|i| if i == j { T::one() } else { T::zero() });
+            Self::deriv(&mut f, pos, dir)
+        });
+        Matrix::from_vector_of_vectors(cols).transpose()
+    }
+}
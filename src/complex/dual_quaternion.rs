@@ -0,0 +1,397 @@
+use crate::{
+    complex::Quaternion,
+    traits::{Conj, Dot},
+    transform::{Directional, Isometry3, Rotation3, Shift},
+    vector::Vector3,
+    Transform,
+};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use num_traits::{Float, Num, NumCast, One, Zero};
+
+/// Dual quaternion `r + εd`, where `ε² = 0`.
+///
+/// Unit dual quaternions represent rigid body motions: the real part is the rotation
+/// and the dual part is `t·r / 2`, where `t` is the translation as pure quaternion.
+#[derive(Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DualQuaternion<T> {
+    real: Quaternion<T>,
+    dual: Quaternion<T>,
+}
+
+impl<T> DualQuaternion<T> {
+    pub fn new(real: Quaternion<T>, dual: Quaternion<T>) -> Self {
+        Self { real, dual }
+    }
+    pub fn into_parts(self) -> (Quaternion<T>, Quaternion<T>) {
+        (self.real, self.dual)
+    }
+    pub fn real_ref(&self) -> &Quaternion<T> {
+        &self.real
+    }
+    pub fn dual_ref(&self) -> &Quaternion<T> {
+        &self.dual
+    }
+    pub fn real_mut(&mut self) -> &mut Quaternion<T> {
+        &mut self.real
+    }
+    pub fn dual_mut(&mut self) -> &mut Quaternion<T> {
+        &mut self.dual
+    }
+}
+
+impl<T> DualQuaternion<T>
+where
+    T: Copy,
+{
+    pub fn real(&self) -> Quaternion<T> {
+        self.real
+    }
+    pub fn dual(&self) -> Quaternion<T> {
+        self.dual
+    }
+}
+
+impl<T> From<(Quaternion<T>, Quaternion<T>)> for DualQuaternion<T> {
+    fn from((real, dual): (Quaternion<T>, Quaternion<T>)) -> Self {
+        Self::new(real, dual)
+    }
+}
+impl<T> From<DualQuaternion<T>> for (Quaternion<T>, Quaternion<T>) {
+    fn from(dq: DualQuaternion<T>) -> Self {
+        dq.into_parts()
+    }
+}
+
+impl<T> Neg for DualQuaternion<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.real, -self.dual)
+    }
+}
+
+impl<T> DualQuaternion<T>
+where
+    T: Neg<Output = T>,
+{
+    /// Quaternion conjugate of both parts, `r* + εd*`.
+    ///
+    /// For unit dual quaternion it is the inverse.
+    pub fn conj(self) -> Self {
+        Self::new(self.real.conj(), self.dual.conj())
+    }
+    /// Dual number conjugate, `r - εd`.
+    pub fn dual_conj(self) -> Self {
+        Self::new(self.real, -self.dual)
+    }
+    /// Combination of quaternion and dual number conjugates, `r* - εd*`.
+    pub fn full_conj(self) -> Self {
+        Self::new(self.real.conj(), -self.dual.conj())
+    }
+}
+
+impl<T> Conj for DualQuaternion<T>
+where
+    T: Neg<Output = T>,
+{
+    fn conj(self) -> Self {
+        DualQuaternion::conj(self)
+    }
+}
+
+impl<T> Add for DualQuaternion<T>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self::new(self.real + other.real, self.dual + other.dual)
+    }
+}
+impl<T> Sub for DualQuaternion<T>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.real - other.real, self.dual - other.dual)
+    }
+}
+impl<T> AddAssign for DualQuaternion<T>
+where
+    Self: Add<Output = Self> + Copy,
+{
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+impl<T> SubAssign for DualQuaternion<T>
+where
+    Self: Sub<Output = Self> + Copy,
+{
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T> Zero for DualQuaternion<T>
+where
+    T: Zero,
+{
+    fn zero() -> Self {
+        Self::new(Quaternion::zero(), Quaternion::zero())
+    }
+    fn is_zero(&self) -> bool {
+        self.real.is_zero() && self.dual.is_zero()
+    }
+}
+
+impl<T> Mul for DualQuaternion<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy,
+{
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.real * other.real,
+            self.real * other.dual + self.dual * other.real,
+        )
+    }
+}
+impl<T> Mul<T> for DualQuaternion<T>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+    fn mul(self, other: T) -> Self {
+        Self::new(self.real * other, self.dual * other)
+    }
+}
+impl<T> Div<T> for DualQuaternion<T>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Self;
+    fn div(self, other: T) -> Self {
+        Self::new(self.real / other, self.dual / other)
+    }
+}
+impl<T> MulAssign for DualQuaternion<T>
+where
+    Self: Mul<Output = Self> + Copy,
+{
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+impl<T> MulAssign<T> for DualQuaternion<T>
+where
+    Self: Mul<T, Output = Self> + Copy,
+{
+    fn mul_assign(&mut self, other: T) {
+        *self = *self * other;
+    }
+}
+impl<T> DivAssign<T> for DualQuaternion<T>
+where
+    Self: Div<T, Output = Self> + Copy,
+{
+    fn div_assign(&mut self, other: T) {
+        *self = *self / other;
+    }
+}
+
+impl<T> One for DualQuaternion<T>
+where
+    T: Zero + One + Sub<Output = T> + Copy,
+{
+    fn one() -> Self {
+        Self::new(Quaternion::one(), Quaternion::zero())
+    }
+}
+
+impl<T> DualQuaternion<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    /// Squared norm as dual number `(r·r, 2 r·d)`.
+    pub fn norm_sqr(self) -> (T, T) {
+        let two = T::one() + T::one();
+        (self.real.norm_sqr(), two * self.real.dot(self.dual))
+    }
+
+    /// Inverse dual quaternion.
+    ///
+    /// Exists only if the real part is non-zero.
+    pub fn inv(self) -> Self {
+        let real = self.real.inv();
+        Self::new(real, -(real * self.dual * real))
+    }
+}
+
+impl<T> DualQuaternion<T>
+where
+    T: Float,
+{
+    /// Norm as dual number `(|r|, r·d / |r|)`.
+    pub fn norm(self) -> (T, T) {
+        let norm = self.real.norm();
+        (norm, self.real.dot(self.dual) / norm)
+    }
+
+    /// Unit dual quaternion, i.e. such that `|r| = 1` and `r·d = 0`.
+    pub fn normalize(self) -> Self {
+        let norm = self.real.norm();
+        let (real, dual) = (self.real / norm, self.dual / norm);
+        Self::new(real, dual - real * real.dot(dual))
+    }
+}
+
+impl<T> DualQuaternion<T>
+where
+    T: Float + NumCast,
+{
+    /// Create unit dual quaternion from rotation and shift, the rotation is performed first.
+    pub fn from_rotation_and_shift(rotation: Rotation3<T>, shift: Shift<T, 3>) -> Self {
+        let real = rotation.into_quaternion();
+        let half = T::from(0.5).unwrap();
+        let trans = Quaternion::from_scalar_and_vector3(T::zero(), shift.into_vector());
+        Self::new(real, trans * real * half)
+    }
+
+    /// Split unit dual quaternion into rotation and shift, the rotation is performed first.
+    pub fn into_rotation_and_shift(self) -> (Rotation3<T>, Shift<T, 3>) {
+        let two = T::from(2.0).unwrap();
+        let trans = self.dual * self.real.conj() * two;
+        (Rotation3::from_quaternion(self.real), trans.xyz().into())
+    }
+
+    /// Translation component of unit dual quaternion.
+    pub fn translation(self) -> Vector3<T> {
+        (self.dual * self.real.conj()).xyz() * T::from(2.0).unwrap()
+    }
+
+    /// Raise unit dual quaternion to the power `t`.
+    ///
+    /// The result is the screw motion with the same axis, `t` times the angle and `t` times the translation along the axis.
+    pub fn powf(self, t: T) -> Self {
+        Isometry3::from(self).powf(t).into()
+    }
+
+    /// Screw linear interpolation between unit dual quaternions.
+    ///
+    /// The motion is performed along the shortest path with constant velocity.
+    pub fn sclerp(self, other: Self, t: T) -> Self {
+        let other = if self.real.dot(other.real) < T::zero() {
+            -other
+        } else {
+            other
+        };
+        self * (self.conj() * other).powf(t)
+    }
+
+    /// Dual quaternion linear blending of two unit dual quaternions.
+    ///
+    /// Cheap approximation of `sclerp` that is commonly used for skinning.
+    pub fn dlb(self, other: Self, t: T) -> Self {
+        Self::blend(&[self, other], &[T::one() - t, t])
+    }
+
+    /// Dual quaternion linear blending of multiple unit dual quaternions with given weights.
+    ///
+    /// Dual quaternions are flipped to the hemisphere of the first one before blending.
+    /// Panics if the number of weights differs from the number of dual quaternions.
+    pub fn blend(dqs: &[Self], weights: &[T]) -> Self {
+        assert_eq!(
+            dqs.len(),
+            weights.len(),
+            "Number of weights must be equal to the number of dual quaternions"
+        );
+        let pivot = match dqs.first() {
+            Some(dq) => dq.real,
+            None => return Self::one(),
+        };
+        dqs.iter()
+            .zip(weights.iter())
+            .fold(Self::zero(), |acc, (dq, w)| {
+                if pivot.dot(dq.real) < T::zero() {
+                    acc - *dq * *w
+                } else {
+                    acc + *dq * *w
+                }
+            })
+            .normalize()
+    }
+}
+
+impl<T> From<Isometry3<T>> for DualQuaternion<T>
+where
+    T: Float + NumCast,
+{
+    fn from(iso: Isometry3<T>) -> Self {
+        let (shift, rotation) = iso.split();
+        Self::from_rotation_and_shift(rotation, shift)
+    }
+}
+impl<T> From<DualQuaternion<T>> for Isometry3<T>
+where
+    T: Float + NumCast,
+{
+    fn from(dq: DualQuaternion<T>) -> Self {
+        let (rotation, shift) = dq.into_rotation_and_shift();
+        Isometry3::new(shift, rotation)
+    }
+}
+impl<T> From<Rotation3<T>> for DualQuaternion<T>
+where
+    T: Zero,
+{
+    fn from(rotation: Rotation3<T>) -> Self {
+        Self::new(rotation.into_quaternion(), Quaternion::zero())
+    }
+}
+impl<T> From<Shift<T, 3>> for DualQuaternion<T>
+where
+    T: Float + NumCast,
+{
+    fn from(shift: Shift<T, 3>) -> Self {
+        Self::from_rotation_and_shift(Rotation3::identity(), shift)
+    }
+}
+
+/// Unit dual quaternion as rigid body transformation.
+impl<T> Transform<Vector3<T>> for DualQuaternion<T>
+where
+    T: Float + NumCast,
+{
+    fn identity() -> Self {
+        Self::one()
+    }
+    fn inv(self) -> Self {
+        self.conj()
+    }
+    fn apply(&self, pos: Vector3<T>) -> Vector3<T> {
+        Rotation3::from_quaternion(self.real).apply(pos) + self.translation()
+    }
+    fn deriv(&self, _pos: Vector3<T>, dir: Vector3<T>) -> Vector3<T> {
+        Rotation3::from_quaternion(self.real).apply(dir)
+    }
+    fn chain(self, other: Self) -> Self {
+        self * other
+    }
+}
+
+impl<T> Directional<Vector3<T>> for DualQuaternion<T>
+where
+    Self: Transform<Vector3<T>>,
+{
+    fn apply_dir(&self, pos: Vector3<T>, dir: Vector3<T>) -> Vector3<T> {
+        self.deriv(pos, dir)
+    }
+    fn apply_normal(&self, pos: Vector3<T>, normal: Vector3<T>) -> Vector3<T> {
+        self.apply_dir(pos, normal)
+    }
+}
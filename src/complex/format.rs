@@ -1,4 +1,4 @@
-use crate::complex::{Complex, Quaternion};
+use crate::complex::{CayleyDickson, Complex, Dual, DualQuaternion, Quaternion, SplitComplex};
 use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
 
 impl<T: Debug> Debug for Complex<T> {
@@ -12,6 +12,28 @@ impl<T: Display> Display for Complex<T> {
     }
 }
 
+impl<T: Debug> Debug for Dual<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "Dual({:?}, {:?})", self.real_ref(), self.dual_ref(),)
+    }
+}
+impl<T: Display> Display for Dual<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "Dual({}, {})", self.real_ref(), self.dual_ref(),)
+    }
+}
+
+impl<T: Debug> Debug for SplitComplex<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "SplitComplex({:?}, {:?})", self.re_ref(), self.im_ref(),)
+    }
+}
+impl<T: Display> Display for SplitComplex<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "SplitComplex({}, {})", self.re_ref(), self.im_ref(),)
+    }
+}
+
 impl<T: Debug> Debug for Quaternion<T> {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
         write!(
@@ -49,3 +71,35 @@ mod tests {
         assert_eq!(format!("{}", q), "Quaternion(1, -2, 3, -4)");
     }
 }
+
+impl<T: Debug> Debug for DualQuaternion<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(
+            f,
+            "DualQuaternion({:?}, {:?})",
+            self.real_ref(),
+            self.dual_ref(),
+        )
+    }
+}
+impl<T: Display> Display for DualQuaternion<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(
+            f,
+            "DualQuaternion({}, {})",
+            self.real_ref(),
+            self.dual_ref(),
+        )
+    }
+}
+
+impl<T, A: Debug> Debug for CayleyDickson<T, A> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "CayleyDickson({:?}, {:?})", self.re_ref(), self.im_ref())
+    }
+}
+impl<T, A: Display> Display for CayleyDickson<T, A> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "CayleyDickson({}, {})", self.re_ref(), self.im_ref())
+    }
+}
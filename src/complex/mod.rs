@@ -1,18 +1,28 @@
 #[cfg(feature = "approx")]
 mod approx;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+mod cayley_dickson;
 mod complex_;
 #[cfg(feature = "rand")]
 mod distr;
+mod dual;
+mod dual_quaternion;
 mod format;
 mod moebius;
 mod quaternion;
+#[cfg(feature = "simd")]
+mod simd;
+mod split_complex;
 #[cfg(all(test, feature = "approx"))]
 mod tests;
 
+pub use cayley_dickson::*;
 pub use complex_::*;
-#[cfg(feature = "rand")]
-pub use distr::*;
+pub use dual::*;
+pub use dual_quaternion::*;
 pub use moebius::*;
 #[cfg(feature = "rand")]
 pub use num_complex::ComplexDistribution;
 pub use quaternion::*;
+pub use split_complex::*;
@@ -1,12 +1,17 @@
 use crate::{
     complex::{Complex, Quaternion},
-    matrix::Matrix2x2,
-    traits::{Dot, Normalize},
+    matrix::{DetStrategy, Matrix2x2},
+    traits::{Dot, Epsilon, NormL1, Normalize},
 };
 use core::ops::{Add, Div, Mul, Neg};
 use num_traits::{Num, NumCast, One, Zero};
 
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Moebius<T> {
     mat: Matrix2x2<T>,
 }
@@ -121,12 +126,12 @@ impl<T> Moebius<T> {
     }
 }
 
-impl<T: Neg<Output = T> + Num + Copy> Moebius<T> {
+impl<T: DetStrategy> Moebius<T> {
     pub fn det(&self) -> T {
         self.mat.det()
     }
 }
-impl<T: Neg<Output = T> + Num + Copy> Normalize for Moebius<T> {
+impl<T: DetStrategy> Normalize for Moebius<T> {
     fn normalize(self) -> Self {
         let det = self.det();
         (self.mat / det).into()
@@ -162,13 +167,24 @@ where
 
 impl<T> Moebius<T>
 where
-    T: Neg<Output = T> + Num + Copy,
+    T: DetStrategy,
 {
     pub fn inv(self) -> Self {
         self.into_matrix().inv().into()
     }
 }
 
+impl<T> Moebius<T>
+where
+    T: DetStrategy + NormL1,
+    <T as NormL1>::Output: Epsilon + Num + Copy,
+{
+    /// Inverse transformation, or `None` if the transformation is degenerate.
+    pub fn try_inv(self) -> Option<Self> {
+        self.into_matrix().try_inv().map(Self::from_matrix)
+    }
+}
+
 impl<T> From<Matrix2x2<T>> for Moebius<T> {
     fn from(mat: Matrix2x2<T>) -> Self {
         Self::from_matrix(mat)
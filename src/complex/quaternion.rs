@@ -1,7 +1,7 @@
 use crate::{
     complex::Complex,
-    matrix::Matrix4x4,
-    traits::{Conj, Dot, NormL1, NormL2, Normalize},
+    matrix::{DetStrategy, Matrix4x4},
+    traits::{Conj, Dot, Epsilon, NormL1, NormL2, Normalize},
     vector::{Vector3, Vector4},
 };
 use core::{
@@ -13,6 +13,11 @@ use num_traits::{Float, Inv, Num, One, Zero};
 /// Quaternion.
 #[repr(transparent)]
 #[derive(Clone, Copy, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Quaternion<T> {
     vec: Vector4<T>,
 }
@@ -471,6 +476,15 @@ where
         self.vec.norm_l1()
     }
 }
+impl<T> Epsilon for Quaternion<T>
+where
+    Self: NormL1<Output = T> + Copy,
+    T: Epsilon,
+{
+    fn is_epsilon(&self) -> bool {
+        self.norm_l1().is_epsilon()
+    }
+}
 impl<T: Float> NormL2 for Quaternion<T> {
     type Output = T;
     fn norm_l2(self) -> T {
@@ -591,6 +605,8 @@ impl<T: Neg<Output = T> + Num + Copy> Rem for Quaternion<T> {
     }
 }
 
+impl<T: Neg<Output = T> + Num + Copy> DetStrategy for Quaternion<T> {}
+
 impl<T: Neg<Output = T> + Num + Copy> Num for Quaternion<T> {
     type FromStrRadixErr = T::FromStrRadixErr;
     fn from_str_radix(_s: &str, _radix: u32) -> Result<Self, Self::FromStrRadixErr> {
@@ -648,3 +664,124 @@ where
         iter.fold(Self::one(), |a, x| a * x)
     }
 }
+
+impl<T: Float> Quaternion<T> {
+    /// Unit vector of the imaginary part.
+    ///
+    /// If the imaginary part is zero then `i` axis is chosen.
+    fn axis(self) -> Vector3<T> {
+        let v = self.xyz();
+        let len = v.length();
+        if len > T::zero() {
+            v / len
+        } else {
+            Vector3::from([T::one(), T::zero(), T::zero()])
+        }
+    }
+    /// Norm and angle between the quaternion and positive real axis.
+    fn to_polar(self) -> (T, T) {
+        (self.norm(), self.xyz().length().atan2(self.w()))
+    }
+    fn from_polar(r: T, theta: T, axis: Vector3<T>) -> Self {
+        (r * theta.cos(), axis * (r * theta.sin())).into()
+    }
+
+    pub fn exp(self) -> Self {
+        Self::from_polar(self.w().exp(), self.xyz().length(), self.axis())
+    }
+    pub fn ln(self) -> Self {
+        let (r, theta) = self.to_polar();
+        (r.ln(), self.axis() * theta).into()
+    }
+    pub fn powf(self, exp: T) -> Self {
+        let (r, theta) = self.to_polar();
+        Self::from_polar(r.powf(exp), theta * exp, self.axis())
+    }
+    pub fn sqrt(self) -> Self {
+        let (r, theta) = self.to_polar();
+        let two = T::one() + T::one();
+        Self::from_polar(r.sqrt(), theta / two, self.axis())
+    }
+}
+
+impl<T> Quaternion<T>
+where
+    T: Float,
+{
+    /// Spherical linear interpolation between unit quaternions without choosing the shortest path.
+    fn slerp_direct(self, other: Self, t: T) -> Self {
+        let theta = self.dot(other).max(-T::one()).min(T::one()).acos();
+        let sin = theta.sin();
+        if sin < T::epsilon().sqrt() {
+            // Quaternions are almost the same or almost opposite, so the great arc is ill-defined.
+            // For close quaternions linear interpolation has the same precision,
+            // and the opposite ones represent the same rotation, so the shortest path is taken.
+            self.nlerp(other, t)
+        } else {
+            (self * ((T::one() - t) * theta).sin() + other * (t * theta).sin()) / sin
+        }
+    }
+
+    /// Normalized linear interpolation between unit quaternions.
+    ///
+    /// It is cheaper than `slerp` but has non-constant angular velocity.
+    /// Shortest path is chosen, so `other` may be replaced by `-other`.
+    pub fn nlerp(self, other: Self, t: T) -> Self {
+        let other = if self.dot(other) < T::zero() {
+            -other
+        } else {
+            other
+        };
+        (self * (T::one() - t) + other * t).normalize()
+    }
+
+    /// Spherical linear interpolation between unit quaternions.
+    ///
+    /// Shortest path is chosen, so `other` may be replaced by `-other`.
+    pub fn slerp(self, other: Self, t: T) -> Self {
+        let other = if self.dot(other) < T::zero() {
+            -other
+        } else {
+            other
+        };
+        self.slerp_direct(other, t)
+    }
+
+    /// Spherical quadrangle interpolation between unit quaternions `self` and `other`
+    /// with control points `a` and `b`.
+    ///
+    /// Control points for a sequence of keyframes should be obtained by `squad_control`.
+    /// Shortest path is chosen, so `other` and `b` may be replaced by `-other` and `-b`.
+    pub fn squad(self, a: Self, b: Self, other: Self, t: T) -> Self {
+        let (b, other) = if self.dot(other) < T::zero() {
+            (-b, -other)
+        } else {
+            (b, other)
+        };
+        let two = T::one() + T::one();
+        self.slerp_direct(other, t)
+            .slerp_direct(a.slerp_direct(b, t), two * t * (T::one() - t))
+    }
+
+    /// Control point of `squad` interpolation for keyframe `self` with neighbours `prev` and `next`.
+    ///
+    /// Neighbours are taken from the same hemisphere as `self`.
+    /// Interpolation between keyframes `q[i]` and `q[i + 1]` is
+    /// `q[i].squad(s[i], s[i + 1], q[i + 1], t)` where `s[i] = q[i].squad_control(q[i - 1], q[i + 1])`,
+    /// that gives the curve with continuous angular velocity.
+    pub fn squad_control(self, prev: Self, next: Self) -> Self {
+        let prev = if self.dot(prev) < T::zero() {
+            -prev
+        } else {
+            prev
+        };
+        let next = if self.dot(next) < T::zero() {
+            -next
+        } else {
+            next
+        };
+        let inv = self.conj();
+        let four = T::from(4).unwrap();
+        self * (-((inv * next).ln() + (inv * prev).ln()) / four).exp()
+    }
+}
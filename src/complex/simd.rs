@@ -0,0 +1,23 @@
+use crate::Quaternion;
+use wide::{f32x4, f64x4};
+
+macro_rules! impl_simd {
+    ($T:ident, $S:ident) => {
+        impl Quaternion<$T> {
+            /// Hamilton product using SIMD instructions.
+            #[inline]
+            pub fn simd_mul(self, other: Self) -> Self {
+                let [w, x, y, z] = self.into_vector().into_array();
+                let [ow, ox, oy, oz] = other.into_vector().into_array();
+                let r = $S::new([ow, ox, oy, oz]) * $S::splat(w);
+                let r = $S::new([-ox, ow, -oz, oy]).mul_add($S::splat(x), r);
+                let r = $S::new([-oy, oz, ow, -ox]).mul_add($S::splat(y), r);
+                let r = $S::new([-oz, -oy, ox, ow]).mul_add($S::splat(z), r);
+                Self::from_vector(r.into())
+            }
+        }
+    };
+}
+
+impl_simd!(f32, f32x4);
+impl_simd!(f64, f64x4);
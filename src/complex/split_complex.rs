@@ -0,0 +1,567 @@
+use crate::{
+    matrix::{DetStrategy, Matrix2x2},
+    traits::{Conj, Dot},
+    vector::Vector2,
+};
+use core::{
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign},
+};
+use num_complex::{Complex as NumComplex, ParseComplexError};
+use num_traits::{Float, Inv, Num, One, Zero};
+
+/// Split-complex number `a + bj`, where `j² = 1`.
+///
+/// Split-complex numbers have zero divisors: `(1 + j)(1 - j) = 0`.
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct SplitComplex<T> {
+    vec: Vector2<T>,
+}
+
+impl<T> SplitComplex<T> {
+    pub fn new(w: T, x: T) -> Self {
+        Self { vec: [w, x].into() }
+    }
+    pub fn from_vector(vec: Vector2<T>) -> Self {
+        Self { vec }
+    }
+    pub fn from_array(arr: [T; 2]) -> Self {
+        Self { vec: arr.into() }
+    }
+    pub fn from_tuple(tup: (T, T)) -> Self {
+        Self { vec: tup.into() }
+    }
+    pub fn into_vector(self) -> Vector2<T> {
+        self.vec
+    }
+    pub fn into_array(self) -> [T; 2] {
+        self.vec.into()
+    }
+    pub fn into_tuple(self) -> (T, T) {
+        self.vec.into()
+    }
+}
+
+impl<T> From<Vector2<T>> for SplitComplex<T> {
+    fn from(vec: Vector2<T>) -> Self {
+        Self::from_vector(vec)
+    }
+}
+impl<T> From<SplitComplex<T>> for Vector2<T> {
+    fn from(sc: SplitComplex<T>) -> Self {
+        sc.into_vector()
+    }
+}
+impl<T> From<[T; 2]> for SplitComplex<T> {
+    fn from(arr: [T; 2]) -> Self {
+        Self::from_array(arr)
+    }
+}
+impl<T> From<SplitComplex<T>> for [T; 2] {
+    fn from(sc: SplitComplex<T>) -> Self {
+        sc.into_array()
+    }
+}
+impl<T> From<(T, T)> for SplitComplex<T> {
+    fn from(tup: (T, T)) -> Self {
+        Self::from_tuple(tup)
+    }
+}
+impl<T> From<SplitComplex<T>> for (T, T) {
+    fn from(sc: SplitComplex<T>) -> Self {
+        sc.into_tuple()
+    }
+}
+
+impl<T: Copy> SplitComplex<T> {
+    pub fn re(&self) -> T {
+        self.vec.x()
+    }
+    pub fn im(&self) -> T {
+        self.vec.y()
+    }
+}
+
+impl<T> SplitComplex<T> {
+    pub fn re_ref(&self) -> &T {
+        self.vec.x_ref()
+    }
+    pub fn im_ref(&self) -> &T {
+        self.vec.y_ref()
+    }
+    pub fn re_mut(&mut self) -> &mut T {
+        self.vec.x_mut()
+    }
+    pub fn im_mut(&mut self) -> &mut T {
+        self.vec.y_mut()
+    }
+}
+
+impl<T: Copy> SplitComplex<T> {
+    pub fn into_matrix(self) -> Matrix2x2<T> {
+        let (re, im) = self.into();
+        Matrix2x2::from([[re, im], [im, re]])
+    }
+}
+
+impl<T> Neg for SplitComplex<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+    fn neg(self) -> Self {
+        (-self.vec).into()
+    }
+}
+
+impl<T> SplitComplex<T>
+where
+    T: Neg<Output = T>,
+{
+    pub fn conj(self) -> Self {
+        let (w, x) = self.into();
+        Self::new(w, -x)
+    }
+}
+
+impl<T> Conj for SplitComplex<T>
+where
+    T: Neg<Output = T>,
+{
+    fn conj(self) -> Self {
+        SplitComplex::conj(self)
+    }
+}
+
+impl<T> Add for SplitComplex<T>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        (self.vec + other.vec).into()
+    }
+}
+impl<T> Add<T> for SplitComplex<T>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+    fn add(self, other: T) -> Self {
+        let (w, x) = self.into();
+        Self::new(w + other, x)
+    }
+}
+impl<T> Sub for SplitComplex<T>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        (self.vec - other.vec).into()
+    }
+}
+impl<T> Sub<T> for SplitComplex<T>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+    fn sub(self, other: T) -> Self {
+        let (w, x) = self.into();
+        Self::new(w - other, x)
+    }
+}
+
+macro_rules! reverse_add_sub {
+    ($T:ident) => {
+        /// Workaround for reverse addition.
+        impl Add<SplitComplex<$T>> for $T {
+            type Output = SplitComplex<$T>;
+            fn add(self, other: SplitComplex<$T>) -> Self::Output {
+                other + self
+            }
+        }
+        /// Workaround for reverse subtraction.
+        impl Sub<SplitComplex<$T>> for $T {
+            type Output = SplitComplex<$T>;
+            fn sub(self, other: SplitComplex<$T>) -> Self::Output {
+                -other + self
+            }
+        }
+    };
+}
+
+reverse_add_sub!(f32);
+reverse_add_sub!(f64);
+
+impl<T> AddAssign for SplitComplex<T>
+where
+    T: AddAssign,
+{
+    fn add_assign(&mut self, other: Self) {
+        self.vec += other.vec;
+    }
+}
+impl<T> AddAssign<T> for SplitComplex<T>
+where
+    T: AddAssign,
+{
+    fn add_assign(&mut self, other: T) {
+        *self.re_mut() += other;
+    }
+}
+impl<T> SubAssign for SplitComplex<T>
+where
+    T: SubAssign,
+{
+    fn sub_assign(&mut self, other: Self) {
+        self.vec -= other.vec;
+    }
+}
+impl<T> SubAssign<T> for SplitComplex<T>
+where
+    T: SubAssign,
+{
+    fn sub_assign(&mut self, other: T) {
+        *self.re_mut() -= other;
+    }
+}
+
+impl<T> Zero for SplitComplex<T>
+where
+    T: Zero,
+{
+    fn zero() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+    fn is_zero(&self) -> bool {
+        self.vec.is_zero()
+    }
+}
+
+impl<T> Mul for SplitComplex<T>
+where
+    T: Add<Output = T> + Mul<Output = T> + Copy,
+{
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.re() * other.re() + self.im() * other.im(),
+            self.re() * other.im() + self.im() * other.re(),
+        )
+    }
+}
+impl<T> Mul<T> for SplitComplex<T>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+    fn mul(self, other: T) -> Self {
+        (self.vec * other).into()
+    }
+}
+
+impl<T> MulAssign for SplitComplex<T>
+where
+    Self: Mul<Output = Self> + Copy,
+{
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+impl<T> MulAssign<T> for SplitComplex<T>
+where
+    Self: Mul<T, Output = Self> + Copy,
+{
+    fn mul_assign(&mut self, other: T) {
+        *self = *self * other;
+    }
+}
+
+impl<T> One for SplitComplex<T>
+where
+    T: Zero + One + Copy,
+{
+    fn one() -> Self {
+        Self::new(T::one(), T::zero())
+    }
+}
+
+impl<T> SplitComplex<T>
+where
+    T: Zero + One,
+{
+    pub fn j() -> Self {
+        Self::new(T::zero(), T::one())
+    }
+}
+
+impl<T> SplitComplex<T>
+where
+    T: Sub<Output = T> + Mul<Output = T> + Copy,
+{
+    /// Square of the Minkowski norm, `z·z* = a² - b²`.
+    ///
+    /// It is not positive definite, for example it is zero for `1 ± j`.
+    pub fn norm_sqr(self) -> T {
+        self.re() * self.re() - self.im() * self.im()
+    }
+}
+impl<T: Float> SplitComplex<T> {
+    /// Minkowski norm, `sqrt(|a² - b²|)`.
+    pub fn norm(self) -> T {
+        self.norm_sqr().abs().sqrt()
+    }
+    /// Hyperbolic angle, defined only inside the right sector `|b| < a`.
+    pub fn arg(self) -> T {
+        (self.im() / self.re()).atanh()
+    }
+    pub fn to_polar(self) -> (T, T) {
+        (self.norm(), self.arg())
+    }
+    /// Create split-complex number `r·(cosh(θ) + j·sinh(θ))`.
+    pub fn from_polar(r: T, theta: T) -> Self {
+        Self::new(r * theta.cosh(), r * theta.sinh())
+    }
+}
+
+impl<T> Div<T> for SplitComplex<T>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Self;
+    fn div(self, other: T) -> Self {
+        (self.vec / other).into()
+    }
+}
+
+impl<T> SplitComplex<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    /// Inverse split-complex number, exists only if `a² ≠ b²`.
+    pub fn inv(self) -> Self {
+        self.conj() / self.norm_sqr()
+    }
+}
+impl<T> Inv for SplitComplex<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    type Output = Self;
+    fn inv(self) -> Self {
+        SplitComplex::inv(self)
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<T> Div for SplitComplex<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    type Output = Self;
+    fn div(self, other: Self) -> Self {
+        self * other.inv()
+    }
+}
+
+impl<T> DivAssign for SplitComplex<T>
+where
+    Self: Div<Output = Self> + Copy,
+{
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+impl<T> DivAssign<T> for SplitComplex<T>
+where
+    Self: Div<T, Output = Self> + Copy,
+{
+    fn div_assign(&mut self, other: T) {
+        *self = *self / other;
+    }
+}
+
+/// Remainder is taken separately for each component in the idempotent basis `(1 ± j) / 2`.
+impl<T: Float> Rem for SplitComplex<T> {
+    type Output = Self;
+    fn rem(self, other: Self) -> Self {
+        let (p, m) = self.into_idempotent();
+        let (op, om) = other.into_idempotent();
+        Self::from_idempotent(p % op, m % om)
+    }
+}
+
+impl<T: Float> DetStrategy for SplitComplex<T> {}
+
+/// Parses the same format as [`Complex`](crate::complex::Complex), e.g. `1+2j`.
+impl<T: Float> Num for SplitComplex<T> {
+    type FromStrRadixErr = ParseComplexError<T::FromStrRadixErr>;
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        NumComplex::from_str_radix(s, radix).map(|nc| Self::new(nc.re, nc.im))
+    }
+}
+
+macro_rules! reverse_mul_div {
+    ($T:ident) => {
+        /// Workaround for reverse multiplication.
+        impl Mul<SplitComplex<$T>> for $T {
+            type Output = SplitComplex<$T>;
+            fn mul(self, other: SplitComplex<$T>) -> Self::Output {
+                other * self
+            }
+        }
+        /// Workaround for reverse division.
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl Div<SplitComplex<$T>> for $T {
+            type Output = SplitComplex<$T>;
+            fn div(self, other: SplitComplex<$T>) -> Self::Output {
+                self * other.inv()
+            }
+        }
+    };
+}
+
+reverse_mul_div!(f32);
+reverse_mul_div!(f64);
+
+impl<T> Dot for SplitComplex<T>
+where
+    T: Add<Output = T> + Mul<Output = T>,
+{
+    type Output = T;
+    fn dot(self, other: Self) -> T {
+        self.vec.dot(other.vec)
+    }
+}
+
+impl<T: Num + Copy> SplitComplex<T> {
+    pub fn powu(&self, exp: u32) -> Self {
+        let (mut base, mut exp, mut acc) = (*self, exp, Self::one());
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc *= base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        acc
+    }
+}
+impl<T: Neg<Output = T> + Num + Copy> SplitComplex<T> {
+    pub fn powi(&self, exp: i32) -> Self {
+        if exp < 0 {
+            self.powu(exp.unsigned_abs()).inv()
+        } else {
+            self.powu(exp as u32)
+        }
+    }
+}
+
+impl<T: Float> SplitComplex<T> {
+    /// Components `(a + b, a - b)` in the idempotent basis `(1 ± j) / 2`.
+    pub fn into_idempotent(self) -> (T, T) {
+        let (w, x) = self.into();
+        (w + x, w - x)
+    }
+    /// Create split-complex number from components in the idempotent basis `(1 ± j) / 2`.
+    pub fn from_idempotent(p: T, m: T) -> Self {
+        let half = T::from(0.5).unwrap();
+        Self::new((p + m) * half, (p - m) * half)
+    }
+
+    /// Apply real function `f` to each component in the idempotent basis.
+    ///
+    /// Any analytic function satisfies `f(a + bj) = (f(a + b) + f(a - b)) / 2 + j·(f(a + b) - f(a - b)) / 2`.
+    fn map_idempotent<F: Fn(T) -> T>(self, f: F) -> Self {
+        let (p, m) = self.into_idempotent();
+        Self::from_idempotent(f(p), f(m))
+    }
+
+    pub fn exp(self) -> Self {
+        self.map_idempotent(T::exp)
+    }
+    /// Natural logarithm, defined only inside the right sector `|b| < a`.
+    pub fn ln(self) -> Self {
+        self.map_idempotent(T::ln)
+    }
+    pub fn sqrt(self) -> Self {
+        self.map_idempotent(T::sqrt)
+    }
+    pub fn cbrt(self) -> Self {
+        self.map_idempotent(T::cbrt)
+    }
+    pub fn powf(self, exp: T) -> Self {
+        self.map_idempotent(|x| x.powf(exp))
+    }
+    pub fn log(self, base: T) -> Self {
+        self.map_idempotent(|x| x.log(base))
+    }
+    pub fn powsc(self, exp: Self) -> Self {
+        (exp * self.ln()).exp()
+    }
+    pub fn expf(self, base: T) -> Self {
+        self.map_idempotent(|x| base.powf(x))
+    }
+    pub fn sin(self) -> Self {
+        self.map_idempotent(T::sin)
+    }
+    pub fn cos(self) -> Self {
+        self.map_idempotent(T::cos)
+    }
+    pub fn tan(self) -> Self {
+        self.map_idempotent(T::tan)
+    }
+    pub fn asin(self) -> Self {
+        self.map_idempotent(T::asin)
+    }
+    pub fn acos(self) -> Self {
+        self.map_idempotent(T::acos)
+    }
+    pub fn atan(self) -> Self {
+        self.map_idempotent(T::atan)
+    }
+    pub fn sinh(self) -> Self {
+        self.map_idempotent(T::sinh)
+    }
+    pub fn cosh(self) -> Self {
+        self.map_idempotent(T::cosh)
+    }
+    pub fn tanh(self) -> Self {
+        self.map_idempotent(T::tanh)
+    }
+    pub fn asinh(self) -> Self {
+        self.map_idempotent(T::asinh)
+    }
+    pub fn acosh(self) -> Self {
+        self.map_idempotent(T::acosh)
+    }
+    pub fn atanh(self) -> Self {
+        self.map_idempotent(T::atanh)
+    }
+    pub fn finv(self) -> Self {
+        self.map_idempotent(T::recip)
+    }
+    pub fn fdiv(self, other: Self) -> Self {
+        self * other.finv()
+    }
+}
+
+impl<T> Sum for SplitComplex<T>
+where
+    Self: Zero + Add,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |a, x| a + x)
+    }
+}
+
+impl<T> Product for SplitComplex<T>
+where
+    Self: One + Mul,
+{
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |a, x| a * x)
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{
+    complex::{CayleyDickson, Octonion, Sedenion},
+    distr::*,
+    traits::Dot,
+    Complex, Quaternion,
+};
+use approx::*;
+use num_traits::{One, Zero};
+use rand_::prelude::*;
+use rand_xorshift::XorShiftRng;
+
+const SAMPLE_ATTEMPTS: usize = 256;
+const EPS: f64 = 1e-9;
+type Of = Octonion<f64>;
+
+/// Basis unit of octonions.
+fn unit(i: usize) -> Of {
+    let mut arr = [0.0; 8];
+    arr[i] = 1.0;
+    Of::from(arr)
+}
+
+#[test]
+fn complex_compatibility() {
+    let mut rng = XorShiftRng::seed_from_u64(0xCD1);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Complex<f64> = rng.sample(&Normal);
+        let b: Complex<f64> = rng.sample(&Normal);
+        let (x, y) = (CayleyDickson::from(a), CayleyDickson::from(b));
+        assert_abs_diff_eq!(Complex::from(x * y), a * b, epsilon = EPS);
+        assert_abs_diff_eq!(Complex::from(x.conj()), a.conj(), epsilon = EPS);
+        assert_abs_diff_eq!(Complex::from(x / y), a / b, epsilon = EPS);
+    }
+}
+
+#[test]
+fn quaternion_compatibility() {
+    let mut rng = XorShiftRng::seed_from_u64(0xCD2);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Quaternion<f64> = rng.sample(&Normal);
+        let b: Quaternion<f64> = rng.sample(&Normal);
+        let (x, y) = (CayleyDickson::from(a), CayleyDickson::from(b));
+        assert_abs_diff_eq!(Quaternion::from(x * y), a * b, epsilon = EPS);
+        assert_abs_diff_eq!(Quaternion::from(x.conj()), a.conj(), epsilon = EPS);
+        assert_abs_diff_eq!(Quaternion::from(x.inv()), a.inv(), epsilon = EPS);
+        assert_abs_diff_eq!(x.norm(), a.norm(), epsilon = EPS);
+    }
+}
+
+#[test]
+fn octonion_units() {
+    let one = Of::one();
+    for i in 1..8 {
+        assert_eq!(unit(i) * unit(i), -one);
+        assert_eq!(unit(i).conj(), -unit(i));
+        for j in (i + 1)..8 {
+            // Imaginary units anticommute.
+            assert_eq!(unit(i) * unit(j), -(unit(j) * unit(i)));
+            assert_eq!((unit(i) * unit(j)).dot(unit(i) * unit(j)), 1.0);
+        }
+    }
+    // Octonions aren't associative.
+    let (a, b, c) = (unit(1), unit(2), unit(4));
+    assert_eq!((a * b) * c, -(a * (b * c)));
+}
+
+#[test]
+fn octonion_algebra() {
+    let mut rng = XorShiftRng::seed_from_u64(0xCD3);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Of = rng.sample(&Normal);
+        let b: Of = rng.sample(&Normal);
+        // Alternativity.
+        assert_abs_diff_eq!((a * a) * b, a * (a * b), epsilon = EPS);
+        assert_abs_diff_eq!((a * b) * b, a * (b * b), epsilon = EPS);
+        assert_abs_diff_eq!((a * b) * a, a * (b * a), epsilon = EPS);
+        // Composition algebra.
+        assert_abs_diff_eq!((a * b).norm(), a.norm() * b.norm(), epsilon = EPS);
+        assert_abs_diff_eq!((a * b).conj(), b.conj() * a.conj(), epsilon = EPS);
+        assert_abs_diff_eq!(a * a.conj(), Of::one() * a.norm_sqr(), epsilon = EPS);
+        // Division algebra.
+        assert_abs_diff_eq!(a * a.inv(), Of::one(), epsilon = EPS);
+        assert_abs_diff_eq!((a * b) / b, a, epsilon = EPS);
+        assert_abs_diff_eq!(a.normalize().norm(), 1.0, epsilon = EPS);
+    }
+}
+
+#[test]
+fn sedenion() {
+    let mut rng = XorShiftRng::seed_from_u64(0xCD4);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Sedenion<f64> = rng.sample(&Normal);
+        // Power associativity.
+        assert_abs_diff_eq!((a * a) * a, a * (a * a), epsilon = EPS);
+        assert_abs_diff_eq!(a * a.inv(), Sedenion::one(), epsilon = EPS);
+        assert_abs_diff_eq!(a.inv() * a, Sedenion::one(), epsilon = EPS);
+    }
+    // Zero divisors.
+    let e = |i: usize| {
+        let (mut re, mut im) = (Of::zero(), Of::zero());
+        if i < 8 {
+            re = unit(i);
+        } else {
+            im = unit(i - 8);
+        }
+        Sedenion::new(re, im)
+    };
+    let (a, b) = (e(3) + e(10), e(6) - e(15));
+    assert!(!a.is_zero() && !b.is_zero());
+    assert!((a * b).is_zero());
+}
@@ -0,0 +1,158 @@
+use crate::{
+    complex::{Dual, Moebius},
+    distr::*,
+    matrix::{Matrix, Matrix3x3},
+    traits::Dot,
+    transform::{Homogeneous3, Isometry3, Linear, Rotation3, Shift},
+    vector::{Vector, Vector3},
+    Complex, Quaternion, Transform,
+};
+use approx::*;
+use core::cmp::Ordering;
+use num_traits::{Float, One};
+use rand_::prelude::*;
+use rand_xorshift::XorShiftRng;
+
+const SAMPLE_ATTEMPTS: usize = 256;
+const EPS: f64 = 1e-9;
+type Df = Dual<f64>;
+
+fn constant<const N: usize>(v: Vector<f64, N>) -> Vector<Df, N> {
+    v.map(Dual::constant)
+}
+fn constant_matrix<const M: usize, const N: usize>(m: Matrix<f64, M, N>) -> Matrix<Df, M, N> {
+    m.map(Dual::constant)
+}
+
+#[test]
+fn arithmetic() {
+    let a = Df::new(2.0, 3.0);
+    let b = Df::new(-1.0, 4.0);
+    assert_abs_diff_eq!(a * b, Df::new(-2.0, 5.0), epsilon = EPS);
+    assert_abs_diff_eq!(a / b * b, a, epsilon = EPS);
+    assert_abs_diff_eq!(a * a.inv(), Df::one(), epsilon = EPS);
+    assert_abs_diff_eq!(Df::eps() * Df::eps(), Df::new(0.0, 0.0), epsilon = EPS);
+    assert_abs_diff_eq!(a * a.conj(), Df::constant(a.norm_sqr()), epsilon = EPS);
+    assert_abs_diff_eq!(a.powi(-3) * a.powu(3), Df::one(), epsilon = EPS);
+    assert_abs_diff_eq!(1.0 / a, a.inv(), epsilon = EPS);
+}
+
+#[test]
+fn ordering() {
+    let a = Df::new(1.0, 2.0);
+    assert!(a < Df::new(2.0, -1.0));
+    assert!(a > Df::new(0.0, 5.0));
+    assert_eq!(a.partial_cmp(&Df::new(1.0, 2.0)), Some(Ordering::Equal));
+    assert_eq!(a.partial_cmp(&Df::new(1.0, 3.0)), None);
+    assert_ne!(a, Df::new(1.0, 3.0));
+}
+
+#[test]
+fn functions() {
+    let mut rng = XorShiftRng::seed_from_u64(0xD0A1);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let x: f64 = rng.gen_range(0.1..0.9);
+        let v = Df::variable(x);
+        assert_abs_diff_eq!(v.exp().dual(), x.exp(), epsilon = EPS);
+        assert_abs_diff_eq!(v.ln().dual(), 1.0 / x, epsilon = EPS);
+        assert_abs_diff_eq!(v.sqrt().dual(), 0.5 / x.sqrt(), epsilon = EPS);
+        assert_abs_diff_eq!(v.sin().dual(), x.cos(), epsilon = EPS);
+        assert_abs_diff_eq!(v.tan().dual(), 1.0 / x.cos().powi(2), epsilon = EPS);
+        assert_abs_diff_eq!(v.asin().dual(), 1.0 / (1.0 - x * x).sqrt(), epsilon = EPS);
+        assert_abs_diff_eq!(v.atanh().dual(), 1.0 / (1.0 - x * x), epsilon = EPS);
+        assert_abs_diff_eq!(v.powf(2.5).dual(), 2.5 * x.powf(1.5), epsilon = EPS);
+        assert_abs_diff_eq!(
+            Float::powf(v, v).dual(),
+            x.powf(x) * (x.ln() + 1.0),
+            epsilon = EPS
+        );
+        assert_abs_diff_eq!(
+            Float::atan2(v, Df::constant(1.0)).dual(),
+            1.0 / (1.0 + x * x),
+            epsilon = EPS
+        );
+    }
+}
+
+#[test]
+fn jacobian() {
+    let mut rng = XorShiftRng::seed_from_u64(0xD0A2);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let m: Matrix3x3<f64> = rng.sample(&Normal);
+        let x: Vector3<f64> = rng.sample(&Normal);
+        let j = Dual::jacobian(|v| constant_matrix(m).dot(v), x);
+        assert_abs_diff_eq!(j, m, epsilon = EPS);
+        let j = Dual::jacobian(|v: Vector3<Df>| v.map(|c| c * c), x);
+        assert_abs_diff_eq!(j, Matrix::diagonal(x * 2.0), epsilon = EPS);
+    }
+}
+
+fn check_deriv<F, G>(apply: F, deriv: G, x: Vector3<f64>, d: Vector3<f64>)
+where
+    F: Fn(Vector3<Df>) -> Vector3<Df>,
+    G: Fn(Vector3<f64>, Vector3<f64>) -> Vector3<f64>,
+{
+    assert_abs_diff_eq!(Dual::deriv(apply, x, d), deriv(x, d), epsilon = 1e-8);
+}
+
+#[test]
+fn transform_deriv() {
+    let mut rng = XorShiftRng::seed_from_u64(0xD0A3);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let x: Vector3<f64> = rng.sample(&Normal);
+        let d: Vector3<f64> = rng.sample(&Normal);
+
+        let r: Rotation3<f64> = rng.sample(&Uniform);
+        let rd = Rotation3::from_quaternion(Quaternion::from(constant(
+            r.into_quaternion().into_vector(),
+        )));
+        check_deriv(|v| rd.apply(v), |x, d| r.deriv(x, d), x, d);
+
+        let s: Shift<f64, 3> = Shift::from(rng.sample::<Vector3<f64>, _>(&Normal));
+        let sd = Shift::from(constant(s.into_vector()));
+        check_deriv(|v| sd.apply(v), |x, d| s.deriv(x, d), x, d);
+
+        let l: Linear<f64, 3> = rng.sample(&Normal);
+        let ld = Linear::from(constant_matrix(l.into_matrix()));
+        check_deriv(|v| ld.apply(v), |x, d| l.deriv(x, d), x, d);
+
+        let i: Isometry3<f64> = rng.sample(&Normal);
+        let id = Isometry3::new(
+            Shift::from(constant(i.shift().into_vector())),
+            Rotation3::from_quaternion(Quaternion::from(constant(
+                i.rotation().into_quaternion().into_vector(),
+            ))),
+        );
+        check_deriv(|v| id.apply(v), |x, d| i.deriv(x, d), x, d);
+
+        let hm: Matrix<f64, 4, 4> = rng.sample(&Normal);
+        let hm = hm + Matrix::<f64, 4, 4>::one() * 4.0;
+        let h = Homogeneous3::from_matrix(hm);
+        let hd = Homogeneous3::from_matrix(constant_matrix(hm));
+        check_deriv(|v| hd.apply(v), |x, d| h.deriv(x, d), x, d);
+    }
+}
+
+#[test]
+fn moebius_deriv() {
+    let mut rng = XorShiftRng::seed_from_u64(0xD0A4);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let m: Moebius<Complex<f64>> = rng.sample(&Invertible);
+        let md = Moebius::from_matrix(
+            m.into_matrix()
+                .map(|c| Complex::new(Dual::constant(c.re()), Dual::constant(c.im()))),
+        );
+        let x: Complex<f64> = rng.sample(&Normal);
+        let d: Complex<f64> = rng.sample(&Normal);
+        let y = md.apply(Complex::new(
+            Df::new(x.re(), d.re()),
+            Df::new(x.im(), d.im()),
+        ));
+        let dy = Complex::new(y.re().dual(), y.im().dual());
+        assert_abs_diff_eq!(
+            dy,
+            Transform::deriv(&m, x, d),
+            epsilon = 1e-6 * (1.0 + dy.norm())
+        );
+    }
+}
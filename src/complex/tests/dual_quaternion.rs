@@ -0,0 +1,146 @@
+use crate::{
+    complex::DualQuaternion,
+    distr::*,
+    transform::{Isometry3, Rotation3, Shift},
+    vector::Vector3,
+    Quaternion, Transform,
+};
+use approx::*;
+use num_traits::{One, Zero};
+use rand_::prelude::*;
+use rand_xorshift::XorShiftRng;
+
+const SAMPLE_ATTEMPTS: usize = 256;
+const EPS: f64 = 1e-12;
+type Dqf = DualQuaternion<f64>;
+
+fn random_motion<R: Rng>(rng: &mut R) -> Dqf {
+    rng.sample::<Isometry3<f64>, _>(&Normal).into()
+}
+
+#[test]
+fn dual_unit() {
+    let eps = Dqf::new(Quaternion::zero(), Quaternion::one());
+    assert_eq!(eps * eps, Dqf::zero());
+    let a = Dqf::new(
+        Quaternion::new(1.0, 2.0, 3.0, 4.0),
+        Quaternion::new(5.0, 6.0, 7.0, 8.0),
+    );
+    assert_eq!(a * Dqf::one(), a);
+    assert_eq!(a.dual_conj().dual_conj(), a);
+    assert_eq!(a.full_conj(), a.conj().dual_conj());
+}
+
+#[test]
+fn arithmetic() {
+    let mut rng = XorShiftRng::seed_from_u64(0xD01);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Dqf = rng.sample(&Normal);
+        let b: Dqf = rng.sample(&Normal);
+        let c: Dqf = rng.sample(&Normal);
+        assert_abs_diff_eq!((a * b) * c, a * (b * c), epsilon = 1e-9);
+        assert_abs_diff_eq!(a * (b + c), a * b + a * c, epsilon = 1e-9);
+        assert_abs_diff_eq!((a * b).conj(), b.conj() * a.conj(), epsilon = 1e-9);
+        assert_abs_diff_eq!(a * a.inv(), Dqf::one(), epsilon = 1e-9);
+        assert_abs_diff_eq!(a.inv() * a, Dqf::one(), epsilon = 1e-9);
+    }
+}
+
+#[test]
+fn normalization() {
+    let mut rng = XorShiftRng::seed_from_u64(0xD02);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Dqf = rng.sample(&Normal);
+        let n = a.normalize();
+        let (r, d) = n.norm();
+        assert_abs_diff_eq!(r, 1.0, epsilon = EPS);
+        assert_abs_diff_eq!(d, 0.0, epsilon = EPS);
+        assert_abs_diff_eq!(n * n.conj(), Dqf::one(), epsilon = 1e-9);
+        // Normalization doesn't change the motion.
+        let m = random_motion(&mut rng);
+        assert_abs_diff_eq!((m * 3.0).normalize(), m, epsilon = EPS);
+    }
+}
+
+#[test]
+fn rigid_motion() {
+    let mut rng = XorShiftRng::seed_from_u64(0xD03);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let iso: Isometry3<f64> = rng.sample(&Normal);
+        let other: Isometry3<f64> = rng.sample(&Normal);
+        let x: Vector3<f64> = rng.sample(&Normal);
+        let (a, b) = (Dqf::from(iso), Dqf::from(other));
+        assert_abs_diff_eq!(a.apply(x), iso.apply(x), epsilon = EPS);
+        assert_abs_diff_eq!(
+            a.chain(b).apply(x),
+            iso.chain(other).apply(x),
+            epsilon = 1e-9
+        );
+        assert_abs_diff_eq!(a.inv().apply(a.apply(x)), x, epsilon = 1e-9);
+        assert_abs_diff_eq!(Isometry3::from(a), iso, epsilon = EPS);
+
+        let (r, s) = a.into_rotation_and_shift();
+        assert_abs_diff_eq!(r, iso.rotation(), epsilon = EPS);
+        assert_abs_diff_eq!(s, iso.shift(), epsilon = EPS);
+    }
+    let s = Shift::from(Vector3::from([1.0, 2.0, 3.0]));
+    let r = Rotation3::new(Vector3::from([0.0, 0.0, 1.0]), 0.5);
+    let x = Vector3::from([0.5, -1.0, 2.0]);
+    assert_abs_diff_eq!(Dqf::from(s).apply(x), s.apply(x), epsilon = EPS);
+    assert_abs_diff_eq!(Dqf::from(r).apply(x), r.apply(x), epsilon = EPS);
+    assert_abs_diff_eq!(
+        (Dqf::from(s) * Dqf::from(r)).apply(x),
+        s.apply(r.apply(x)),
+        epsilon = EPS
+    );
+}
+
+#[test]
+fn sclerp() {
+    let mut rng = XorShiftRng::seed_from_u64(0xD04);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a = random_motion(&mut rng);
+        let b = random_motion(&mut rng);
+        let x: Vector3<f64> = rng.sample(&Normal);
+        assert_abs_diff_eq!(a.sclerp(b, 0.0).apply(x), a.apply(x), epsilon = 1e-9);
+        assert_abs_diff_eq!(a.sclerp(b, 1.0).apply(x), b.apply(x), epsilon = 1e-9);
+        // Double cover doesn't affect the path.
+        assert_abs_diff_eq!(
+            a.sclerp(-b, 0.3).apply(x),
+            a.sclerp(b, 0.3).apply(x),
+            epsilon = 1e-9
+        );
+        assert_abs_diff_eq!(
+            a.sclerp(b, 0.3).apply(x),
+            Isometry3::from(a).sclerp(Isometry3::from(b), 0.3).apply(x),
+            epsilon = 1e-9
+        );
+        let h = (a.conj() * b).powf(0.5);
+        assert_abs_diff_eq!((h * h).apply(x), (a.conj() * b).apply(x), epsilon = 1e-9);
+    }
+}
+
+#[test]
+fn blending() {
+    let mut rng = XorShiftRng::seed_from_u64(0xD05);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a = random_motion(&mut rng);
+        let b = random_motion(&mut rng);
+        let x: Vector3<f64> = rng.sample(&Normal);
+        assert_abs_diff_eq!(a.dlb(b, 0.0).apply(x), a.apply(x), epsilon = 1e-9);
+        assert_abs_diff_eq!(a.dlb(-b, 1.0).apply(x), b.apply(x), epsilon = 1e-9);
+        let c = a.dlb(b, 0.4);
+        let (r, d) = c.norm();
+        assert_abs_diff_eq!(r, 1.0, epsilon = EPS);
+        assert_abs_diff_eq!(d, 0.0, epsilon = EPS);
+        // Blending of equal motions.
+        assert_abs_diff_eq!(Dqf::blend(&[a, -a, a], &[0.2, 0.3, 0.5]), a, epsilon = EPS);
+    }
+    // Pure translations are blended linearly.
+    let s = [1.0, 2.0, 4.0].map(|k| Dqf::from(Shift::from(Vector3::fill(k))));
+    assert_abs_diff_eq!(
+        Dqf::blend(&s, &[0.25, 0.25, 0.5]).apply(Vector3::zero()),
+        Vector3::fill(2.75),
+        epsilon = EPS
+    );
+}
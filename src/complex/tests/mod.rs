@@ -1,4 +1,15 @@
+#[cfg(feature = "rand")]
+mod cayley_dickson;
 mod complex_;
 #[cfg(feature = "rand")]
+mod dual;
+#[cfg(feature = "rand")]
+mod dual_quaternion;
+#[cfg(feature = "rand")]
 mod moebius;
 mod quaternion;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(all(feature = "simd", feature = "rand"))]
+mod simd;
+mod split_complex;
@@ -58,3 +58,19 @@ fn quaternion_directional_derivation() {
         );
     }
 }
+
+#[test]
+fn fallible_inversion() {
+    let mut rng = XorShiftRng::seed_from_u64(0xBEEF3);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Moebius<Complex<f64>> = rng.sample(Invertible);
+        assert_eq!(a.try_inv(), Some(a.inv()));
+    }
+
+    let (b, d, k) = (
+        Complex::new(1.0, 2.0),
+        Complex::new(-3.0, 1.0),
+        Complex::new(0.5, -2.0),
+    );
+    assert!(Moebius::new(b * k, b, d * k, d).try_inv().is_none());
+}
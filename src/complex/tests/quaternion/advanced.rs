@@ -1,6 +1,6 @@
 use crate::{distr::*, traits::Dot, Quaternion};
 use approx::*;
-use num_traits::One;
+use num_traits::{One, Zero};
 use rand_::prelude::*;
 use rand_xorshift::XorShiftRng;
 
@@ -109,3 +109,50 @@ fn into_matrix() {
         );
     }
 }
+
+#[test]
+fn exp_ln() {
+    let mut rng = XorShiftRng::seed_from_u64(0xFEED4);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Qf = rng.sample(&NonZero);
+        assert_abs_diff_eq!(a.ln().exp(), a, epsilon = 1e-12);
+        assert_abs_diff_eq!(a.ln().w(), a.norm().ln(), epsilon = 1e-12);
+
+        let (w, x) = (a.w(), a.x());
+        let c = crate::Complex::new(w, x).exp();
+        assert_abs_diff_eq!(
+            Qf::new(w, x, 0.0, 0.0).exp(),
+            Qf::new(c.re(), c.im(), 0.0, 0.0),
+            epsilon = 1e-12
+        );
+
+        let v = Qf::from_scalar_and_vector3(0.0, a.xyz());
+        assert_abs_diff_eq!(v.exp().norm(), 1.0, epsilon = 1e-12);
+        assert_abs_diff_eq!((v * 0.3).exp() * (v * 0.7).exp(), v.exp(), epsilon = 1e-12);
+    }
+    assert_abs_diff_eq!(Qf::one().ln(), Qf::zero());
+    assert_abs_diff_eq!(
+        (-Qf::one()).ln(),
+        Qf::i() * core::f64::consts::PI,
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn powers() {
+    let mut rng = XorShiftRng::seed_from_u64(0xFEED5);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Qf = rng.sample(&NonZero);
+        assert_abs_diff_eq!(a.powf(2.0), a * a, epsilon = 1e-12);
+        assert_abs_diff_eq!(a.powf(-1.0), a.inv(), epsilon = 1e-12);
+        assert_abs_diff_eq!(a.powf(0.5), a.sqrt(), epsilon = 1e-12);
+        assert_abs_diff_eq!(a.sqrt() * a.sqrt(), a, epsilon = 1e-12);
+        assert!(a.sqrt().w() >= 0.0);
+    }
+    assert_abs_diff_eq!(
+        Qf::new(-4.0, 0.0, 0.0, 0.0).sqrt(),
+        Qf::i() * 2.0,
+        epsilon = 1e-12
+    );
+    assert_eq!(Qf::zero().powf(2.0), Qf::zero());
+}
@@ -0,0 +1,135 @@
+use crate::{distr::*, traits::Dot, Quaternion};
+use approx::*;
+use rand_::prelude::*;
+use rand_xorshift::XorShiftRng;
+
+const SAMPLE_ATTEMPTS: usize = 256;
+type Qf = Quaternion<f64>;
+
+/// Angle between rotations represented by unit quaternions.
+fn angle(a: Qf, b: Qf) -> f64 {
+    2.0 * a.dot(b).abs().min(1.0).acos()
+}
+
+#[test]
+fn slerp() {
+    let mut rng = XorShiftRng::seed_from_u64(0x5E1);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Qf = rng.sample(&Unit);
+        let b: Qf = rng.sample(&Unit);
+        assert_abs_diff_eq!(a.slerp(b, 0.0), a, epsilon = 1e-12);
+        assert_abs_diff_eq!(angle(a.slerp(b, 1.0), b), 0.0, epsilon = 1e-6);
+
+        let full = angle(a, b);
+        for &t in [0.25, 0.5, 0.9].iter() {
+            let c = a.slerp(b, t);
+            assert_abs_diff_eq!(c.norm(), 1.0, epsilon = 1e-12);
+            // Constant angular velocity along the shortest arc.
+            assert_abs_diff_eq!(angle(a, c), t * full, epsilon = 1e-6);
+            assert_abs_diff_eq!(angle(c, b), (1.0 - t) * full, epsilon = 1e-6);
+        }
+        // Double cover doesn't change the result.
+        assert_abs_diff_eq!(
+            angle(a.slerp(-b, 0.3), a.slerp(b, 0.3)),
+            0.0,
+            epsilon = 1e-6
+        );
+    }
+}
+
+#[test]
+fn slerp_close() {
+    let a = Qf::new(1.0, 0.0, 0.0, 0.0);
+    let b = Qf::new(1.0, 1e-10, 0.0, 0.0).normalize();
+    let c = a.slerp(b, 0.5);
+    assert_abs_diff_eq!(c.norm(), 1.0, epsilon = 1e-12);
+    assert_abs_diff_eq!(c.x(), 0.5e-10, epsilon = 1e-20);
+}
+
+#[test]
+fn nlerp() {
+    let mut rng = XorShiftRng::seed_from_u64(0x5E2);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Qf = rng.sample(&Unit);
+        let b: Qf = rng.sample(&Unit);
+        let full = angle(a, b);
+        let c = a.nlerp(b, 0.3);
+        assert_abs_diff_eq!(c.norm(), 1.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(angle(a, c) + angle(c, b), full, epsilon = 1e-6);
+        assert_abs_diff_eq!(
+            angle(a.nlerp(-b, 0.5), a.slerp(b, 0.5)),
+            0.0,
+            epsilon = 1e-6
+        );
+    }
+}
+
+#[test]
+fn squad() {
+    let mut rng = XorShiftRng::seed_from_u64(0x5E3);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let q: [Qf; 4] = [
+            rng.sample(&Unit),
+            rng.sample(&Unit),
+            rng.sample(&Unit),
+            rng.sample(&Unit),
+        ];
+        let s1 = q[1].squad_control(q[0], q[2]);
+        let s2 = q[2].squad_control(q[1], q[3]);
+        assert_abs_diff_eq!(
+            angle(q[1].squad(s1, s2, q[2], 0.0), q[1]),
+            0.0,
+            epsilon = 1e-6
+        );
+        assert_abs_diff_eq!(
+            angle(q[1].squad(s1, s2, q[2], 1.0), q[2]),
+            0.0,
+            epsilon = 1e-6
+        );
+        assert_abs_diff_eq!(q[1].squad(s1, s2, q[2], 0.4).norm(), 1.0, epsilon = 1e-12);
+        // Shortest path doesn't depend on the sign of keyframes.
+        assert_abs_diff_eq!(
+            angle(
+                q[1].squad(s1, s2, q[2], 0.4),
+                q[1].squad(s1, -s2, -q[2], 0.4)
+            ),
+            0.0,
+            epsilon = 1e-6
+        );
+    }
+}
+
+#[test]
+fn squad_antipodal() {
+    let (q0, q1) = (Qf::new(1.0, 0.0, 0.0, 0.0), Qf::new(0.0, 1.0, 0.0, 0.0));
+    // Control points represent the same rotation but lie in the opposite hemispheres.
+    let a = Qf::new(0.0, 0.0, 1.0, 0.0);
+    let b = -a;
+    for &t in [0.0, 0.3, 0.5, 1.0].iter() {
+        let c = q0.squad(a, b, q1, t);
+        assert_abs_diff_eq!(c.norm(), 1.0, epsilon = 1e-12);
+    }
+    assert_abs_diff_eq!(angle(q0.squad(a, b, q1, 1.0), q1), 0.0, epsilon = 1e-6);
+}
+
+#[test]
+fn squad_continuity() {
+    let mut rng = XorShiftRng::seed_from_u64(0x5E4);
+    let (h, eps) = (1e-5, 1e-4);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let q: [Qf; 3] = [rng.sample(&Unit), rng.sample(&Unit), rng.sample(&Unit)];
+        // Keep keyframes close enough to avoid ambiguity.
+        let q = [q[0], q[0].slerp(q[1], 0.3), q[0].slerp(q[2], 0.5)];
+        let s = [
+            q[0].squad_control(q[0], q[1]),
+            q[1].squad_control(q[0], q[2]),
+            q[2].squad_control(q[1], q[2]),
+        ];
+        // Angular velocity at the keyframe `q[1]` is the same from both sides.
+        let left = q[0].squad(s[0], s[1], q[1], 1.0 - h);
+        let right = q[1].squad(s[1], s[2], q[2], h);
+        let vl = (q[1] - left) / h;
+        let vr = (right - q[1]) / h;
+        assert_abs_diff_eq!(vl, vr, epsilon = eps);
+    }
+}
@@ -1,3 +1,5 @@
 #[cfg(feature = "rand")]
 mod advanced;
 mod basic;
+#[cfg(feature = "rand")]
+mod interp;
@@ -0,0 +1,44 @@
+use crate::{
+    complex::{DualQuaternion, Moebius},
+    Complex, Quaternion,
+};
+
+#[test]
+fn compact_form() {
+    let c = Complex::new(1.0, -2.0);
+    assert_eq!(serde_json::to_string(&c).unwrap(), "[1.0,-2.0]");
+    assert_eq!(
+        serde_json::from_str::<Complex<f64>>("[1.0,-2.0]").unwrap(),
+        c
+    );
+
+    let q = Quaternion::new(0.1, 0.2, 0.3, 0.4);
+    assert_eq!(serde_json::to_string(&q).unwrap(), "[0.1,0.2,0.3,0.4]");
+    assert_eq!(
+        serde_json::from_str::<Quaternion<f64>>("[0.1,0.2,0.3,0.4]").unwrap(),
+        q
+    );
+}
+
+#[test]
+fn round_trip() {
+    let m = Moebius::new(
+        Complex::new(1.0, 2.0),
+        Complex::new(3.0, 4.0),
+        Complex::new(5.0, 6.0),
+        Complex::new(7.0, 8.0),
+    );
+    let s = serde_json::to_string(&m).unwrap();
+    assert_eq!(s, "[[[1.0,2.0],[3.0,4.0]],[[5.0,6.0],[7.0,8.0]]]");
+    assert_eq!(
+        serde_json::from_str::<Moebius<Complex<f64>>>(&s).unwrap(),
+        m
+    );
+
+    let dq = DualQuaternion::new(
+        Quaternion::new(1.0, 0.0, 0.0, 0.0),
+        Quaternion::new(0.0, 0.5, 1.0 / 3.0, 0.0),
+    );
+    let s = serde_json::to_string(&dq).unwrap();
+    assert_eq!(serde_json::from_str::<DualQuaternion<f64>>(&s).unwrap(), dq);
+}
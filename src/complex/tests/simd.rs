@@ -0,0 +1,20 @@
+use crate::{distr::*, Quaternion};
+use approx::*;
+use rand_::prelude::*;
+use rand_xorshift::XorShiftRng;
+
+const SAMPLE_ATTEMPTS: usize = 256;
+
+#[test]
+fn quaternion_mul() {
+    let mut rng = XorShiftRng::seed_from_u64(0xBEEF);
+    for _ in 0..SAMPLE_ATTEMPTS {
+        let a: Quaternion<f64> = rng.sample(&Normal);
+        let b: Quaternion<f64> = rng.sample(&Normal);
+        assert_abs_diff_eq!(a.simd_mul(b), a * b, epsilon = 1e-12);
+
+        let a = Quaternion::from_vector(a.into_vector().map(|x| x as f32));
+        let b = Quaternion::from_vector(b.into_vector().map(|x| x as f32));
+        assert_abs_diff_eq!(a.simd_mul(b), a * b, epsilon = 1e-4);
+    }
+}
@@ -0,0 +1,70 @@
+use crate::complex::SplitComplex;
+use ::approx::*;
+use num_traits::{Num, One, Zero};
+
+type Sc = SplitComplex<f64>;
+const EPS: f64 = 1e-12;
+
+#[test]
+fn new() {
+    let z = Sc::new(1.0, 2.0);
+    assert_abs_diff_eq!(z.re(), 1.0);
+    assert_abs_diff_eq!(z.im(), 2.0);
+}
+
+#[test]
+fn mul() {
+    let a = Sc::new(1.0, 2.0);
+    let b = Sc::new(5.0, 6.0);
+    let c = Sc::new(17.0, 16.0);
+    assert_abs_diff_eq!(a * b, c);
+    assert_abs_diff_eq!(b * a, c);
+    assert_abs_diff_eq!(Sc::j() * Sc::j(), Sc::one());
+}
+
+#[test]
+fn zero_divisors() {
+    let a = Sc::new(1.0, 1.0);
+    let b = Sc::new(1.0, -1.0);
+    assert!((a * b).is_zero());
+    assert_abs_diff_eq!(a.norm_sqr(), 0.0);
+}
+
+#[test]
+fn inv() {
+    let a = Sc::new(3.0, 1.0);
+    assert_abs_diff_eq!(a * a.inv(), Sc::one(), epsilon = EPS);
+    assert_abs_diff_eq!(2.0 / a, Sc::new(3.0, -1.0) / 4.0, epsilon = EPS);
+    assert_abs_diff_eq!(a.powi(-2) * a.powu(2), Sc::one(), epsilon = EPS);
+}
+
+#[test]
+fn polar() {
+    let z = Sc::from_polar(2.0, 0.5);
+    let (r, theta) = z.to_polar();
+    assert_abs_diff_eq!(r, 2.0, epsilon = EPS);
+    assert_abs_diff_eq!(theta, 0.5, epsilon = EPS);
+    assert_abs_diff_eq!(
+        Sc::new(0.0, 0.5).exp(),
+        Sc::from_polar(1.0, 0.5),
+        epsilon = EPS
+    );
+}
+
+#[test]
+fn functions() {
+    let z = Sc::new(1.5, 0.5);
+    assert_abs_diff_eq!(z.exp().ln(), z, epsilon = EPS);
+    assert_abs_diff_eq!(z.sqrt() * z.sqrt(), z, epsilon = EPS);
+    assert_abs_diff_eq!((z + z).exp(), z.exp() * z.exp(), epsilon = EPS);
+    let (s, c) = (z.sin(), z.cos());
+    assert_abs_diff_eq!(s * s + c * c, Sc::one(), epsilon = EPS);
+    assert_abs_diff_eq!(z.powf(3.0), z * z * z, epsilon = EPS);
+    assert_abs_diff_eq!(z.tanh().atanh(), z, epsilon = EPS);
+}
+
+#[test]
+fn parse() {
+    let z = Sc::from_str_radix("1.5+2j", 10).unwrap();
+    assert_abs_diff_eq!(z, Sc::new(1.5, 2.0));
+}
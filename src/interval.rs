@@ -0,0 +1,577 @@
+//! Interval arithmetic for validated numerics.
+
+use crate::{
+    matrix::DetStrategy,
+    traits::{Conj, Dot, Epsilon, NormL1, NormL2, NormLInf},
+    Vector,
+};
+#[cfg(feature = "approx")]
+use approx::{abs_diff_eq, AbsDiffEq};
+use core::{
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign},
+};
+use num_traits::{Float, Num, One, Zero};
+
+/// Floating-point type that allows to step to the adjacent representable values.
+pub trait Ulp: Float {
+    /// Least value that is greater than `self`.
+    fn ulp_up(self) -> Self;
+    /// Greatest value that is less than `self`.
+    fn ulp_down(self) -> Self;
+}
+
+macro_rules! derive_ulp {
+    ($T:ident) => {
+        impl Ulp for $T {
+            fn ulp_up(self) -> Self {
+                if self.is_nan() || self == $T::INFINITY {
+                    self
+                } else if self == 0.0 {
+                    $T::from_bits(1)
+                } else if self > 0.0 {
+                    $T::from_bits(self.to_bits() + 1)
+                } else {
+                    $T::from_bits(self.to_bits() - 1)
+                }
+            }
+            fn ulp_down(self) -> Self {
+                -(-self).ulp_up()
+            }
+        }
+    };
+}
+
+derive_ulp!(f32);
+derive_ulp!(f64);
+
+/// Closed interval `[lo, hi]` of real numbers.
+///
+/// All operations round outward, so the result is guaranteed to enclose the exact result for any values from the operands.
+/// Elementary functions other than `sqrt` rely on the underlying implementation being accurate within one ulp.
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct Interval<T> {
+    lo: T,
+    hi: T,
+}
+
+impl<T> Interval<T> {
+    pub fn lo_ref(&self) -> &T {
+        &self.lo
+    }
+    pub fn hi_ref(&self) -> &T {
+        &self.hi
+    }
+    pub fn into_bounds(self) -> (T, T) {
+        (self.lo, self.hi)
+    }
+}
+
+impl<T: Copy> Interval<T> {
+    pub fn lo(&self) -> T {
+        self.lo
+    }
+    pub fn hi(&self) -> T {
+        self.hi
+    }
+}
+
+impl<T: PartialOrd> Interval<T> {
+    /// Create interval from its bounds.
+    ///
+    /// Panics if `lo > hi`.
+    pub fn new(lo: T, hi: T) -> Self {
+        assert!(lo <= hi, "Lower bound must not exceed the upper one");
+        Self { lo, hi }
+    }
+    /// Check that the interval contains value.
+    pub fn contains(&self, x: T) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+    /// Check that the interval contains all values of other interval.
+    pub fn encloses(&self, other: &Self) -> bool {
+        self.lo <= other.lo && other.hi <= self.hi
+    }
+}
+
+impl<T: Copy> Interval<T> {
+    /// Degenerate interval containing only single value.
+    pub fn point(x: T) -> Self {
+        Self { lo: x, hi: x }
+    }
+}
+
+impl<T: Float> Interval<T> {
+    /// Interval containing all real numbers.
+    pub fn entire() -> Self {
+        Self {
+            lo: T::neg_infinity(),
+            hi: T::infinity(),
+        }
+    }
+    /// Smallest interval that contains both intervals.
+    pub fn hull(self, other: Self) -> Self {
+        Self {
+            lo: self.lo.min(other.lo),
+            hi: self.hi.max(other.hi),
+        }
+    }
+    /// Intersection of two intervals, or `None` if they don't overlap.
+    pub fn intersection(self, other: Self) -> Option<Self> {
+        let (lo, hi) = (self.lo.max(other.lo), self.hi.min(other.hi));
+        if lo <= hi {
+            Some(Self { lo, hi })
+        } else {
+            None
+        }
+    }
+    pub fn mid(&self) -> T {
+        let half = T::from(0.5).unwrap();
+        self.lo * half + self.hi * half
+    }
+    pub fn width(&self) -> T {
+        self.hi - self.lo
+    }
+}
+
+impl<T: Ulp> Interval<T> {
+    /// Create interval from bounds that are computed with rounding to nearest.
+    fn outward(lo: T, hi: T) -> Self {
+        Self {
+            lo: lo.ulp_down(),
+            hi: hi.ulp_up(),
+        }
+    }
+    /// Smallest interval that encloses all the values.
+    fn enclose(values: [T; 4]) -> Self {
+        let (lo, hi) = values[1..]
+            .iter()
+            .fold((values[0], values[0]), |(lo, hi), &x| {
+                (lo.min(x), hi.max(x))
+            });
+        Self::outward(lo, hi)
+    }
+
+    pub fn abs(self) -> Self {
+        if self.lo >= T::zero() {
+            self
+        } else if self.hi <= T::zero() {
+            -self
+        } else {
+            Self {
+                lo: T::zero(),
+                hi: self.hi.max(-self.lo),
+            }
+        }
+    }
+    /// Square, that is tighter than `self * self` when the interval contains zero.
+    pub fn sqr(self) -> Self {
+        let a = self.abs();
+        let lo = if a.lo.is_zero() {
+            T::zero()
+        } else {
+            (a.lo * a.lo).ulp_down()
+        };
+        Self {
+            lo,
+            hi: (a.hi * a.hi).ulp_up(),
+        }
+    }
+    pub fn powu(self, exp: u32) -> Self {
+        match exp {
+            0 => Self::one(),
+            n if n % 2 == 0 => self.sqr().powu(n / 2),
+            n => self * self.powu(n - 1),
+        }
+    }
+    /// Square root of the non-negative part of the interval.
+    pub fn sqrt(self) -> Self {
+        let lo = self.lo.max(T::zero());
+        Self {
+            lo: lo.sqrt().ulp_down().max(T::zero()),
+            hi: self.hi.sqrt().ulp_up(),
+        }
+    }
+    pub fn exp(self) -> Self {
+        Self {
+            lo: self.lo.exp().ulp_down().max(T::zero()),
+            hi: self.hi.exp().ulp_up(),
+        }
+    }
+    /// Natural logarithm of the positive part of the interval.
+    ///
+    /// Lower bound is `-∞` if the interval contains zero or negative values.
+    /// The result is NaN if the interval has no non-negative values at all.
+    pub fn ln(self) -> Self {
+        Self::outward(self.lo.max(T::zero()).ln(), self.hi.ln())
+    }
+}
+
+impl<T> Neg for Interval<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl<T: Ulp> Add for Interval<T> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self::outward(self.lo + other.lo, self.hi + other.hi)
+    }
+}
+impl<T: Ulp> Sub for Interval<T> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self::outward(self.lo - other.hi, self.hi - other.lo)
+    }
+}
+/// Product of zero and infinite bounds is zero, because infinite bound isn't a value contained in the interval.
+impl<T: Ulp> Mul for Interval<T> {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        let mul = |a: T, b: T| {
+            if a.is_zero() || b.is_zero() {
+                T::zero()
+            } else {
+                a * b
+            }
+        };
+        Self::enclose([
+            mul(self.lo, other.lo),
+            mul(self.lo, other.hi),
+            mul(self.hi, other.lo),
+            mul(self.hi, other.hi),
+        ])
+    }
+}
+/// Division by interval that contains zero results in the entire real line.
+impl<T: Ulp> Div for Interval<T> {
+    type Output = Self;
+    fn div(self, other: Self) -> Self {
+        if other.lo <= T::zero() && T::zero() <= other.hi {
+            Self::entire()
+        } else {
+            Self::enclose([
+                self.lo / other.lo,
+                self.lo / other.hi,
+                self.hi / other.lo,
+                self.hi / other.hi,
+            ])
+        }
+    }
+}
+/// Remainder with the sign of dividend, enclosed using `|a % b| < |b|` and `|a % b| <= |a|`.
+impl<T: Ulp> Rem for Interval<T> {
+    type Output = Self;
+    fn rem(self, other: Self) -> Self {
+        let m = other.abs().hi;
+        Self {
+            lo: self.lo.max(-m).min(T::zero()),
+            hi: self.hi.min(m).max(T::zero()),
+        }
+    }
+}
+
+impl<T> AddAssign for Interval<T>
+where
+    Self: Add<Output = Self> + Copy,
+{
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+impl<T> SubAssign for Interval<T>
+where
+    Self: Sub<Output = Self> + Copy,
+{
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+impl<T> MulAssign for Interval<T>
+where
+    Self: Mul<Output = Self> + Copy,
+{
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+impl<T> DivAssign for Interval<T>
+where
+    Self: Div<Output = Self> + Copy,
+{
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl<T: Ulp> Zero for Interval<T> {
+    fn zero() -> Self {
+        Self::point(T::zero())
+    }
+    fn is_zero(&self) -> bool {
+        self.lo.is_zero() && self.hi.is_zero()
+    }
+}
+impl<T: Ulp> One for Interval<T> {
+    fn one() -> Self {
+        Self::point(T::one())
+    }
+}
+
+/// Parsed value is widened, because its decimal representation may be not exact.
+impl<T: Ulp> Num for Interval<T> {
+    type FromStrRadixErr = T::FromStrRadixErr;
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        T::from_str_radix(s, radix).map(|x| Self::outward(x, x))
+    }
+}
+
+/// Determinant and inverse are computed using cofactor expansion,
+/// because pivoting in LU decomposition requires comparison of values.
+impl<T: Ulp> DetStrategy for Interval<T> {}
+
+/// Interval is considered to be epsilon if it may contain zero.
+impl<T: Ulp> Epsilon for Interval<T> {
+    fn is_epsilon(&self) -> bool {
+        self.lo <= T::zero() && T::zero() <= self.hi
+    }
+}
+
+impl<T: Ulp> Dot for Interval<T> {
+    type Output = Self;
+    fn dot(self, other: Self) -> Self {
+        self * other
+    }
+}
+impl<T: Ulp> Conj for Interval<T> {
+    fn conj(self) -> Self {
+        self
+    }
+}
+impl<T: Ulp> NormL1 for Interval<T> {
+    type Output = Self;
+    fn norm_l1(self) -> Self {
+        self.abs()
+    }
+}
+impl<T: Ulp> NormL2 for Interval<T> {
+    type Output = Self;
+    fn norm_l2(self) -> Self {
+        self.abs()
+    }
+    fn norm_l2_sqr(self) -> Self {
+        self.sqr()
+    }
+}
+impl<T: Ulp> NormLInf for Interval<T> {
+    type Output = Self;
+    fn norm_l_inf(self) -> Self {
+        self.abs()
+    }
+}
+
+impl<T: Ulp, const N: usize> NormL2 for Vector<Interval<T>, N> {
+    type Output = Interval<T>;
+    fn norm_l2_sqr(self) -> Interval<T> {
+        self.map(|x| x.sqr()).sum()
+    }
+    fn norm_l2(self) -> Interval<T> {
+        self.norm_l2_sqr().sqrt()
+    }
+}
+/// Intervals aren't totally ordered, so the maximum is taken for each bound separately.
+impl<T: Ulp, const N: usize> NormLInf for Vector<Interval<T>, N> {
+    type Output = Interval<T>;
+    fn norm_l_inf(self) -> Interval<T> {
+        let abs = self.map(|x| x.abs());
+        Interval {
+            lo: abs.map(|x| x.lo).fold_first(T::max),
+            hi: abs.map(|x| x.hi).fold_first(T::max),
+        }
+    }
+}
+
+impl<T> Sum for Interval<T>
+where
+    Self: Zero + Add,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |a, x| a + x)
+    }
+}
+impl<T> Product for Interval<T>
+where
+    Self: One + Mul,
+{
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |a, x| a * x)
+    }
+}
+
+impl<T: Debug> Debug for Interval<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "Interval({:?}, {:?})", self.lo, self.hi)
+    }
+}
+impl<T: Display> Display for Interval<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "[{}, {}]", self.lo, self.hi)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> AbsDiffEq for Interval<T>
+where
+    T: AbsDiffEq<Epsilon = T> + Copy,
+{
+    type Epsilon = T;
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        abs_diff_eq!(self.lo, other.lo, epsilon = epsilon)
+            && abs_diff_eq!(self.hi, other.hi, epsilon = epsilon)
+    }
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::{
+        distr::{Normal, Uniform},
+        matrix::Matrix3x3,
+        transform::{Linear, Rotation3},
+        vector::Vector3,
+        Matrix, Quaternion, Transform,
+    };
+    use approx::assert_abs_diff_eq;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+    type If = Interval<f64>;
+
+    fn random_point<R: Rng>(rng: &mut R, x: If) -> f64 {
+        x.lo() + (x.hi() - x.lo()) * rng.gen::<f64>()
+    }
+
+    #[test]
+    fn ulp() {
+        assert!(1.0f64.ulp_up() > 1.0);
+        assert!(1.0f64.ulp_down() < 1.0);
+        assert!(0.0f64.ulp_up() > 0.0);
+        assert!((-0.0f64).ulp_down() < 0.0);
+        assert_eq!(f64::MAX.ulp_up(), f64::INFINITY);
+        assert_eq!((-1.0f32).ulp_up().ulp_down(), -1.0);
+        assert_eq!(f64::INFINITY.ulp_down(), f64::MAX);
+    }
+
+    #[test]
+    fn mul_infinite() {
+        let zero = If::point(0.0);
+        let p = zero * If::entire();
+        assert!(p.contains(0.0));
+        assert!(p.lo().is_finite() && p.hi().is_finite());
+        assert_eq!(If::new(0.0, 1.0) * If::entire(), If::entire());
+        let p = If::new(0.0, 1.0) * If::new(2.0, f64::INFINITY);
+        assert!(p.contains(0.0) && p.hi() == f64::INFINITY && p.lo() <= 0.0);
+    }
+
+    #[test]
+    fn enclosure() {
+        let mut rng = XorShiftRng::seed_from_u64(0x1A1);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let (a, b, c, d): (f64, f64, f64, f64) = (rng.gen(), rng.gen(), rng.gen(), rng.gen());
+            let c = c + 1.0;
+            let x = If::new(f64::min(a, b) - 0.5, f64::max(a, b));
+            let y = If::new(c, c + d);
+            for _ in 0..16 {
+                let (p, q) = (random_point(&mut rng, x), random_point(&mut rng, y));
+                assert!((x + y).contains(p + q));
+                assert!((x - y).contains(p - q));
+                assert!((x * y).contains(p * q));
+                assert!((x / y).contains(p / q));
+                assert!((x % y).contains(p % q));
+                assert!(x.sqr().contains(p * p));
+                assert!(x.powu(3).contains(p * p * p));
+                assert!(y.sqrt().contains(q.sqrt()));
+                assert!(x.exp().contains(p.exp()));
+                assert!(y.ln().contains(q.ln()));
+            }
+        }
+    }
+
+    #[test]
+    fn ln_domain() {
+        let x = If::new(-1.0, 1.0).ln();
+        assert_eq!(x.lo(), f64::NEG_INFINITY);
+        assert!(x.contains(0.0) && x.hi() < 1e-12);
+        assert_eq!(If::new(0.0, 1.0).ln().lo(), f64::NEG_INFINITY);
+        assert!(If::new(-2.0, -1.0).ln().hi().is_nan());
+    }
+
+    #[test]
+    fn decimal() {
+        let x: If = Num::from_str_radix("0.1", 10).unwrap();
+        let sum = (0..10).map(|_| x).sum::<If>();
+        assert!(sum.contains(1.0));
+        assert!(sum.width() > 0.0);
+        assert!((If::one() / If::new(-1.0, 1.0)).encloses(&If::point(1e300)));
+    }
+
+    #[test]
+    fn det() {
+        let mut rng = XorShiftRng::seed_from_u64(0x1A2);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let m: Matrix3x3<f64> = rng.sample(&Normal);
+            let det = m.map(If::point).det();
+            assert_abs_diff_eq!(det.mid(), m.det(), epsilon = 1e-12);
+            assert!(det.width() < 1e-12);
+        }
+        let m = Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]).map(If::point);
+        assert!(m.det().contains(0.0));
+        assert!(m.try_inv().is_none());
+    }
+
+    #[test]
+    fn norms() {
+        let v = Vector::from([If::new(-1.0, 2.0), If::new(-3.0, -2.0), If::point(0.0)]);
+        let l1 = v.norm_l1();
+        assert!(l1.encloses(&If::new(2.0, 5.0)) && l1.width() < 3.0 + 1e-12);
+        assert_eq!(v.norm_l_inf().into_bounds(), (2.0, 3.0));
+        let l2 = v.norm_l2();
+        assert!(l2.contains(2.0) && l2.contains(13.0f64.sqrt()));
+    }
+
+    #[test]
+    fn transform_box() {
+        let mut rng = XorShiftRng::seed_from_u64(0x1A3);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let center: Vector3<f64> = rng.sample(&Normal);
+            let bx = center.map(|c| If::new(c - 0.1, c + 0.1));
+
+            let lin: Linear<f64, 3> = rng.sample(&Normal);
+            let lin_box = Linear::from(lin.into_matrix().map(If::point)).apply(bx);
+            let rot: Rotation3<f64> = rng.sample(&Uniform);
+            let quat = Quaternion::from(rot.into_quaternion().into_vector().map(If::point));
+            let rot_box = Rotation3::from_quaternion(quat).apply(bx);
+
+            for _ in 0..16 {
+                let p = bx.map(|x| random_point(&mut rng, x));
+                let q = lin.apply(p);
+                assert!(lin_box.zip(q).into_iter().all(|(b, x)| b.contains(x)));
+                let q = rot.apply(p);
+                assert!(rot_box.zip(q).into_iter().all(|(b, x)| b.contains(x)));
+            }
+        }
+    }
+}
@@ -0,0 +1,189 @@
+//! Types with memory layout of GLSL `std140` and `std430` blocks.
+//!
+//! Both layouts align `vec3` and the columns of `mat3` to 16 bytes,
+//! so the same types could be used for uniform and storage buffers.
+
+use crate::{
+    matrix::Matrix3x3,
+    vector::{Vector3, Vector4},
+};
+use num_traits::Zero;
+
+/// Three-component vector padded to 16 bytes, as `vec3` in `std140` and `std430` layouts.
+#[repr(C, align(16))]
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub struct PaddedVector3<T> {
+    vec: Vector3<T>,
+    pad: T,
+}
+
+impl<T: Zero> PaddedVector3<T> {
+    pub fn new(vec: Vector3<T>) -> Self {
+        Self {
+            vec,
+            pad: T::zero(),
+        }
+    }
+}
+impl<T> PaddedVector3<T> {
+    pub fn into_vector(self) -> Vector3<T> {
+        self.vec
+    }
+    pub fn as_vector(&self) -> &Vector3<T> {
+        &self.vec
+    }
+    pub fn as_mut_vector(&mut self) -> &mut Vector3<T> {
+        &mut self.vec
+    }
+}
+
+impl<T: Zero> From<Vector3<T>> for PaddedVector3<T> {
+    fn from(vec: Vector3<T>) -> Self {
+        Self::new(vec)
+    }
+}
+impl<T> From<PaddedVector3<T>> for Vector3<T> {
+    fn from(padded: PaddedVector3<T>) -> Self {
+        padded.into_vector()
+    }
+}
+
+/// Column-major 3x3 matrix with each column padded to 16 bytes, as `mat3` in `std140` and `std430` layouts.
+#[repr(C, align(16))]
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub struct PaddedMatrix3x3<T> {
+    cols: [PaddedVector3<T>; 3],
+}
+
+impl<T: Zero + Copy> PaddedMatrix3x3<T> {
+    pub fn new(mat: Matrix3x3<T>) -> Self {
+        Self {
+            cols: [0, 1, 2].map(|j| PaddedVector3::new(mat.col(j))),
+        }
+    }
+}
+impl<T: Copy> PaddedMatrix3x3<T> {
+    pub fn into_matrix(self) -> Matrix3x3<T> {
+        Matrix3x3::from_array_of_vectors(self.cols.map(|c| c.into_vector())).transpose()
+    }
+    /// Columns with padding, each of them is laid out as `vec4`.
+    pub fn into_padded_columns(self) -> [Vector4<T>; 3] {
+        self.cols.map(|c| {
+            let [x, y, z] = c.vec.into_array();
+            Vector4::from([x, y, z, c.pad])
+        })
+    }
+}
+
+impl<T: Zero + Copy> From<Matrix3x3<T>> for PaddedMatrix3x3<T> {
+    fn from(mat: Matrix3x3<T>) -> Self {
+        Self::new(mat)
+    }
+}
+impl<T: Copy> From<PaddedMatrix3x3<T>> for Matrix3x3<T> {
+    fn from(padded: PaddedMatrix3x3<T>) -> Self {
+        padded.into_matrix()
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+mod pod {
+    use super::{PaddedMatrix3x3, PaddedVector3};
+    use bytemuck::{Pod, Zeroable};
+
+    // Padded types have no implicit padding only for 4-byte scalars.
+    macro_rules! derive_pod {
+        ($T:ident) => {
+            unsafe impl Zeroable for PaddedVector3<$T> {}
+            unsafe impl Pod for PaddedVector3<$T> {}
+            unsafe impl Zeroable for PaddedMatrix3x3<$T> {}
+            unsafe impl Pod for PaddedMatrix3x3<$T> {}
+        };
+    }
+
+    derive_pod!(f32);
+    derive_pod!(i32);
+    derive_pod!(u32);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Matrix;
+    use core::mem::{align_of, size_of};
+
+    #[test]
+    fn sizes() {
+        assert_eq!(size_of::<PaddedVector3<f32>>(), 16);
+        assert_eq!(align_of::<PaddedVector3<f32>>(), 16);
+        assert_eq!(size_of::<[PaddedVector3<f32>; 2]>(), 32);
+        assert_eq!(size_of::<PaddedMatrix3x3<f32>>(), 48);
+        assert_eq!(align_of::<PaddedMatrix3x3<f32>>(), 16);
+    }
+
+    #[test]
+    fn column_major() {
+        let m = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let p = PaddedMatrix3x3::from(m);
+        assert_eq!(
+            p.into_padded_columns(),
+            [[1, 4, 7, 0], [2, 5, 8, 0], [3, 6, 9, 0]].map(Vector4::from)
+        );
+        assert_eq!(p.into_matrix(), m);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    mod pod {
+        use super::*;
+        use crate::{
+            complex::{Complex, Quaternion},
+            vector::Vector,
+        };
+        use bytemuck::{bytes_of, cast_slice, from_bytes, Zeroable};
+
+        #[test]
+        fn vector() {
+            assert_eq!(size_of::<Vector<f32, 3>>(), 12);
+            assert_eq!(align_of::<Vector<f32, 3>>(), align_of::<f32>());
+            let vs = [
+                Vector::from([1.0f32, 2.0, 3.0]),
+                Vector::from([4.0, 5.0, 6.0]),
+            ];
+            let fs: &[f32] = cast_slice(&vs);
+            assert_eq!(fs, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+            assert_eq!(cast_slice::<f32, Vector<f32, 3>>(fs), &vs);
+            assert_eq!(Vector::<u16, 5>::zeroed(), Vector::fill(0));
+        }
+
+        #[test]
+        fn matrix() {
+            assert_eq!(size_of::<Matrix<f64, 2, 3>>(), 48);
+            let m = Matrix::from([[1u32, 2, 3], [4, 5, 6]]);
+            let us: &[u32] = cast_slice(core::slice::from_ref(&m));
+            assert_eq!(us, &[1, 2, 3, 4, 5, 6]);
+        }
+
+        #[test]
+        fn complex() {
+            let c = Complex::new(1.0f32, 2.0);
+            assert_eq!(cast_slice::<Complex<f32>, f32>(&[c]), &[1.0, 2.0]);
+            let q = Quaternion::new(1i32, 2, 3, 4);
+            assert_eq!(cast_slice::<Quaternion<i32>, i32>(&[q]), &[1, 2, 3, 4]);
+        }
+
+        #[test]
+        fn padded() {
+            let v = PaddedVector3::from(Vector::from([1.0f32, 2.0, 3.0]));
+            assert_eq!(cast_slice::<u8, f32>(bytes_of(&v)), &[1.0, 2.0, 3.0, 0.0]);
+
+            let m = PaddedMatrix3x3::from(Matrix::from([[1u32, 2, 3], [4, 5, 6], [7, 8, 9]]));
+            let bytes = bytes_of(&m);
+            assert_eq!(bytes.len(), 48);
+            assert_eq!(
+                cast_slice::<u8, u32>(bytes),
+                &[1, 4, 7, 0, 2, 5, 8, 0, 3, 6, 9, 0]
+            );
+            assert_eq!(*from_bytes::<PaddedMatrix3x3<u32>>(bytes), m);
+        }
+    }
+}
@@ -61,9 +61,12 @@
 #[cfg(feature = "std")]
 extern crate std;
 
+pub mod batch;
 pub mod complex;
 #[cfg(feature = "rand")]
 pub mod distr;
+pub mod interval;
+pub mod layout;
 pub mod matrix;
 pub mod traits;
 pub mod transform;
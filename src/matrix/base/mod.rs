@@ -1,6 +1,7 @@
 mod convert;
 mod init;
 mod iter;
+mod slice;
 
 pub use iter::*;
 
@@ -0,0 +1,29 @@
+use super::Matrix;
+use crate::vector::{flat_count, SliceLengthError};
+use core::slice;
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// Reinterpret flat slice of elements as slice of row-major matrices without copying.
+    ///
+    /// Slice length must be a multiple of `M * N`, otherwise `Err` is returned.
+    pub fn slice_from_flat(s: &[T]) -> Result<&[Self], SliceLengthError> {
+        let count = flat_count(s.len(), M * N)?;
+        // Matrix is `repr(transparent)` over `[[T; N]; M]`, so its layout is the same as `M * N` consecutive elements.
+        Ok(unsafe { slice::from_raw_parts(s.as_ptr() as *const Self, count) })
+    }
+    /// Reinterpret flat mutable slice of elements as mutable slice of row-major matrices without copying.
+    ///
+    /// Slice length must be a multiple of `M * N`, otherwise `Err` is returned.
+    pub fn slice_from_flat_mut(s: &mut [T]) -> Result<&mut [Self], SliceLengthError> {
+        let count = flat_count(s.len(), M * N)?;
+        Ok(unsafe { slice::from_raw_parts_mut(s.as_mut_ptr() as *mut Self, count) })
+    }
+    /// Reinterpret slice of matrices as flat slice of their elements in row-major order without copying.
+    pub fn flatten(s: &[Self]) -> &[T] {
+        unsafe { slice::from_raw_parts(s.as_ptr() as *const T, s.len() * M * N) }
+    }
+    /// Reinterpret mutable slice of matrices as flat mutable slice of their elements in row-major order without copying.
+    pub fn flatten_mut(s: &mut [Self]) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(s.as_mut_ptr() as *mut T, s.len() * M * N) }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::Matrix;
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Copy,
+{
+    /// Take submatrix from original matrix by removing `i`-th row and `j`-th column.
+    ///
+    /// Output dimensions must be `R = M - 1` and `C = N - 1`, otherwise the function panics.
+    pub fn minor<const R: usize, const C: usize>(&self, i: usize, j: usize) -> Matrix<T, R, C> {
+        assert!(
+            R + 1 == M && C + 1 == N,
+            "Minor must be one row and one column smaller"
+        );
+        assert!(i < M && j < N);
+        Matrix::indices().map(|(y, x)| self[(y + (y >= i) as usize, x + (x >= j) as usize)])
+    }
+
+    /// Take `R`x`C` block which top left element is located at `(row, col)`.
+    ///
+    /// Panics if the block doesn't fit into the matrix.
+    pub fn block<const R: usize, const C: usize>(&self, row: usize, col: usize) -> Matrix<T, R, C> {
+        assert!(
+            row + R <= M && col + C <= N,
+            "Block is out of matrix bounds"
+        );
+        Matrix::indices().map(|(i, j)| self[(row + i, col + j)])
+    }
+
+    /// Take `R` consecutive rows starting from `row`.
+    pub fn fixed_rows<const R: usize>(&self, row: usize) -> Matrix<T, R, N> {
+        self.block(row, 0)
+    }
+
+    /// Take `C` consecutive columns starting from `col`.
+    pub fn fixed_columns<const C: usize>(&self, col: usize) -> Matrix<T, M, C> {
+        self.block(0, col)
+    }
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
+    /// Replace `R`x`C` block which top left element is located at `(row, col)`.
+    ///
+    /// Panics if the block doesn't fit into the matrix.
+    pub fn set_block<const R: usize, const C: usize>(
+        &mut self,
+        row: usize,
+        col: usize,
+        block: Matrix<T, R, C>,
+    ) {
+        assert!(
+            row + R <= M && col + C <= N,
+            "Block is out of matrix bounds"
+        );
+        for (i, block_row) in block.into_vector_of_vectors().into_iter().enumerate() {
+            for (j, x) in block_row.into_iter().enumerate() {
+                self[(row + i, col + j)] = x;
+            }
+        }
+    }
+}
@@ -0,0 +1,6 @@
+use crate::Matrix;
+use bytemuck::{Pod, Zeroable};
+
+// Matrix is `#[repr(transparent)]` over vector of rows, so it has no padding.
+unsafe impl<T: Zeroable, const M: usize, const N: usize> Zeroable for Matrix<T, M, N> {}
+unsafe impl<T: Pod, const M: usize, const N: usize> Pod for Matrix<T, M, N> {}
@@ -0,0 +1,226 @@
+use crate::{Matrix, Vector};
+use num_traits::{Float, One, Zero};
+
+/// Cholesky decomposition of a symmetric positive-definite matrix.
+///
+/// Represents the matrix `A` as `A = L·Lᵀ`, where `L` is a lower triangular matrix with positive diagonal.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Cholesky<T, const N: usize> {
+    l: Matrix<T, N, N>,
+}
+
+/// LDLᵀ decomposition of a symmetric matrix.
+///
+/// Represents the matrix `A` as `A = L·D·Lᵀ`, where `L` is a lower unitriangular matrix
+/// and `D` is a diagonal matrix.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Ldlt<T, const N: usize> {
+    /// `L` below the diagonal and `D` on the diagonal packed into a single matrix.
+    ld: Matrix<T, N, N>,
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Float,
+{
+    /// Cholesky decomposition of the matrix.
+    ///
+    /// The matrix is assumed to be symmetric, only its lower triangle is used.
+    /// Returns `None` if the matrix is not positive-definite.
+    pub fn cholesky(&self) -> Option<Cholesky<T, N>> {
+        let mut l = Matrix::<T, N, N>::zero();
+        for j in 0..N {
+            let d = (0..j).fold(self[(j, j)], |s, k| s - l[(j, k)] * l[(j, k)]);
+            if d <= T::zero() || d.is_nan() {
+                return None;
+            }
+            let d = d.sqrt();
+            l[(j, j)] = d;
+            for i in (j + 1)..N {
+                let s = (0..j).fold(self[(i, j)], |s, k| s - l[(i, k)] * l[(j, k)]);
+                l[(i, j)] = s / d;
+            }
+        }
+        Some(Cholesky { l })
+    }
+
+    /// LDLᵀ decomposition of the matrix.
+    ///
+    /// The matrix is assumed to be symmetric, only its lower triangle is used.
+    /// Unlike Cholesky decomposition it also works for indefinite and positive semi-definite matrices:
+    /// pivot is considered to be zero if its absolute value doesn't exceed
+    /// the largest absolute value of the matrix elements multiplied by `N` and machine epsilon,
+    /// and then the corresponding column of `L` is set to zero.
+    ///
+    /// Returns `None` if a zero pivot has non-zero elements below it,
+    /// because such matrix (e.g. `[[0, 1], [1, 0]]`) cannot be decomposed without pivoting.
+    pub fn ldlt(&self) -> Option<Ldlt<T, N>> {
+        let scale = self.iter().fold(T::zero(), |a, x| a.max(x.abs()));
+        let threshold = scale * T::epsilon() * T::from(N).unwrap();
+
+        let mut ld = Matrix::<T, N, N>::zero();
+        for j in 0..N {
+            let d = (0..j).fold(self[(j, j)], |s, k| {
+                s - ld[(j, k)] * ld[(j, k)] * ld[(k, k)]
+            });
+            let zero = d.abs() <= threshold;
+            if !zero {
+                ld[(j, j)] = d;
+            }
+            for i in (j + 1)..N {
+                let s = (0..j).fold(self[(i, j)], |s, k| {
+                    s - ld[(i, k)] * ld[(j, k)] * ld[(k, k)]
+                });
+                if !zero {
+                    ld[(i, j)] = s / d;
+                } else if s.abs() > threshold {
+                    return None;
+                }
+            }
+        }
+        Some(Ldlt { ld })
+    }
+}
+
+impl<T, const N: usize> Cholesky<T, N>
+where
+    T: Float,
+{
+    /// Lower triangular matrix `L`.
+    pub fn l(&self) -> Matrix<T, N, N> {
+        self.l
+    }
+
+    /// Determinant of the decomposed matrix.
+    pub fn det(&self) -> T {
+        let det = (0..N).fold(T::one(), |a, i| a * self.l[(i, i)]);
+        det * det
+    }
+
+    /// Solve `A·x = b` for `x`.
+    pub fn solve(&self, b: Vector<T, N>) -> Vector<T, N> {
+        let mut x = b;
+        for i in 0..N {
+            for j in 0..i {
+                x[i] = x[i] - self.l[(i, j)] * x[j];
+            }
+            x[i] = x[i] / self.l[(i, i)];
+        }
+        for i in (0..N).rev() {
+            for j in (i + 1)..N {
+                x[i] = x[i] - self.l[(j, i)] * x[j];
+            }
+            x[i] = x[i] / self.l[(i, i)];
+        }
+        x
+    }
+
+    /// Solve `A·X = B` for `X`.
+    pub fn solve_matrix<const K: usize>(&self, b: Matrix<T, N, K>) -> Matrix<T, N, K> {
+        Matrix::from_vector_of_vectors(
+            b.transpose()
+                .into_vector_of_vectors()
+                .map(|col| self.solve(col)),
+        )
+        .transpose()
+    }
+
+    /// Inverse of the decomposed matrix.
+    ///
+    /// The result is exactly symmetric.
+    pub fn inverse(&self) -> Matrix<T, N, N> {
+        // Inverse of `L` is also lower triangular.
+        let mut li = Matrix::<T, N, N>::zero();
+        for j in 0..N {
+            li[(j, j)] = T::one() / self.l[(j, j)];
+            for i in (j + 1)..N {
+                let s = (j..i).fold(T::zero(), |s, k| s - self.l[(i, k)] * li[(k, j)]);
+                li[(i, j)] = s / self.l[(i, i)];
+            }
+        }
+        // `A⁻¹ = L⁻ᵀ·L⁻¹`, compute lower triangle and mirror it.
+        let mut inv = Matrix::<T, N, N>::zero();
+        for i in 0..N {
+            for j in 0..=i {
+                let s = (i..N).fold(T::zero(), |s, k| s + li[(k, i)] * li[(k, j)]);
+                inv[(i, j)] = s;
+                inv[(j, i)] = s;
+            }
+        }
+        inv
+    }
+}
+
+impl<T, const N: usize> Ldlt<T, N>
+where
+    T: Float,
+{
+    /// Lower unitriangular matrix `L`.
+    pub fn l(&self) -> Matrix<T, N, N> {
+        Matrix::indices().map(|(i, j)| {
+            if i > j {
+                self.ld[(i, j)]
+            } else if i == j {
+                T::one()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    /// Diagonal of the matrix `D`.
+    pub fn d(&self) -> Vector<T, N> {
+        Vector::indices().map(|i| self.ld[(i, i)])
+    }
+
+    /// Whether the decomposed matrix is singular.
+    pub fn is_singular(&self) -> bool {
+        (0..N).any(|i| self.ld[(i, i)] == T::zero())
+    }
+
+    /// Whether the decomposed matrix is positive semi-definite.
+    pub fn is_positive_semidefinite(&self) -> bool {
+        (0..N).all(|i| self.ld[(i, i)] >= T::zero())
+    }
+
+    /// Determinant of the decomposed matrix.
+    pub fn det(&self) -> T {
+        (0..N).fold(T::one(), |a, i| a * self.ld[(i, i)])
+    }
+
+    /// Solve `A·x = b` for `x`.
+    ///
+    /// If the matrix is singular then the result contains non-finite values.
+    pub fn solve(&self, b: Vector<T, N>) -> Vector<T, N> {
+        let mut x = b;
+        for i in 0..N {
+            for j in 0..i {
+                x[i] = x[i] - self.ld[(i, j)] * x[j];
+            }
+        }
+        for i in 0..N {
+            x[i] = x[i] / self.ld[(i, i)];
+        }
+        for i in (0..N).rev() {
+            for j in (i + 1)..N {
+                x[i] = x[i] - self.ld[(j, i)] * x[j];
+            }
+        }
+        x
+    }
+
+    /// Solve `A·X = B` for `X`.
+    pub fn solve_matrix<const K: usize>(&self, b: Matrix<T, N, K>) -> Matrix<T, N, K> {
+        Matrix::from_vector_of_vectors(
+            b.transpose()
+                .into_vector_of_vectors()
+                .map(|col| self.solve(col)),
+        )
+        .transpose()
+    }
+
+    /// Inverse of the decomposed matrix.
+    pub fn inverse(&self) -> Matrix<T, N, N> {
+        self.solve_matrix(Matrix::one())
+    }
+}
@@ -1,10 +1,10 @@
 use crate::{
     distr::{Invertible, Normal},
+    matrix::DetStrategy,
     traits::{Epsilon, NormL1},
     Matrix,
 };
-use core::{marker::PhantomData, ops::Neg};
-use num_traits::Num;
+use core::marker::PhantomData;
 use rand_::{distributions::Distribution, Rng};
 
 /// Per-component matrix distribution.
@@ -35,14 +35,14 @@ where
     Normal: Distribution<T>,
 {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Matrix<T, M, N> {
-        rng.sample(&MatrixDistribution::new(self))
+        rng.sample(MatrixDistribution::new(self))
     }
 }
 
 impl<T, const N: usize> Distribution<Matrix<T, N, N>> for Invertible
 where
     Normal: Distribution<Matrix<T, N, N>>,
-    T: Neg<Output = T> + Num + NormL1 + Copy,
+    T: DetStrategy + NormL1,
     <T as NormL1>::Output: Epsilon,
 {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Matrix<T, N, N> {
@@ -0,0 +1,117 @@
+use crate::{Matrix, Vector};
+use num_traits::{Float, One};
+
+/// Maximum number of Jacobi sweeps.
+const MAX_SWEEPS: usize = 64;
+
+/// Eigendecomposition of a symmetric matrix.
+///
+/// Represents the matrix `A` as `A = V·D·Vᵀ`, where `D` is a diagonal matrix of eigenvalues
+/// and `V` is an orthogonal matrix which columns are the corresponding eigenvectors.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SymmetricEigen<T, const N: usize> {
+    values: Vector<T, N>,
+    vectors: Matrix<T, N, N>,
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Float,
+{
+    /// Eigendecomposition of the symmetric matrix using cyclic Jacobi rotations.
+    ///
+    /// The matrix is assumed to be symmetric.
+    /// Eigenvalues are sorted in ascending order.
+    /// Eigenvectors form a proper rotation matrix, i.e. its determinant is `1`.
+    pub fn symmetric_eigen(&self) -> SymmetricEigen<T, N> {
+        let mut a = *self;
+        let mut v = Matrix::<T, N, N>::one();
+
+        let scale = a.iter().fold(T::zero(), |s, x| s + *x * *x);
+        let threshold = T::epsilon() * T::epsilon() * scale;
+
+        for _ in 0..MAX_SWEEPS {
+            let mut off = T::zero();
+            for p in 0..N {
+                for q in (p + 1)..N {
+                    off = off + a[(p, q)] * a[(p, q)];
+                }
+            }
+            if off <= threshold {
+                break;
+            }
+
+            for p in 0..N {
+                for q in (p + 1)..N {
+                    let apq = a[(p, q)];
+                    if apq == T::zero() {
+                        continue;
+                    }
+                    let theta = (a[(q, q)] - a[(p, p)]) / (apq + apq);
+                    let t = theta.signum() / (theta.abs() + theta.hypot(T::one()));
+                    let c = T::one() / t.hypot(T::one());
+                    let s = t * c;
+
+                    for k in 0..N {
+                        let (akp, akq) = (a[(k, p)], a[(k, q)]);
+                        a[(k, p)] = c * akp - s * akq;
+                        a[(k, q)] = s * akp + c * akq;
+                    }
+                    for k in 0..N {
+                        let (apk, aqk) = (a[(p, k)], a[(q, k)]);
+                        a[(p, k)] = c * apk - s * aqk;
+                        a[(q, k)] = s * apk + c * aqk;
+                    }
+                    for k in 0..N {
+                        let (vkp, vkq) = (v[(k, p)], v[(k, q)]);
+                        v[(k, p)] = c * vkp - s * vkq;
+                        v[(k, q)] = s * vkp + c * vkq;
+                    }
+                }
+            }
+        }
+
+        let mut order = Vector::<usize, N>::indices();
+        for i in 1..N {
+            let mut j = i;
+            while j > 0 && a[(order[j], order[j])] < a[(order[j - 1], order[j - 1])] {
+                order.as_mut_array().swap(j, j - 1);
+                j -= 1;
+            }
+        }
+
+        let values = order.map(|i| a[(i, i)]);
+        let mut vectors = Matrix::indices().map(|(i, j)| v[(i, order[j])]);
+        if N > 0 && vectors.lu().det() < T::zero() {
+            for i in 0..N {
+                vectors[(i, N - 1)] = -vectors[(i, N - 1)];
+            }
+        }
+
+        SymmetricEigen { values, vectors }
+    }
+}
+
+impl<T, const N: usize> SymmetricEigen<T, N>
+where
+    T: Float,
+{
+    /// Eigenvalues in ascending order.
+    pub fn eigenvalues(&self) -> Vector<T, N> {
+        self.values
+    }
+
+    /// Orthogonal matrix which columns are the eigenvectors.
+    pub fn eigenvectors(&self) -> Matrix<T, N, N> {
+        self.vectors
+    }
+
+    /// Compose the original matrix back from the decomposition.
+    pub fn recompose(&self) -> Matrix<T, N, N> {
+        Matrix::indices().map(|(i, j)| {
+            (0..N).fold(T::zero(), |s, k| {
+                s + self.vectors[(i, k)] * self.values[k] * self.vectors[(j, k)]
+            })
+        })
+    }
+}
@@ -0,0 +1,206 @@
+use crate::{matrix::DetStrategy, Matrix, Vector};
+use num_traits::{Float, One};
+
+/// LU decomposition of a square matrix with partial pivoting.
+///
+/// Represents the matrix `A` as `P·A = L·U`, where `P` is a permutation matrix,
+/// `L` is a lower unitriangular matrix and `U` is an upper triangular matrix.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Lu<T, const N: usize> {
+    /// `L` (below the diagonal) and `U` (on and above the diagonal) packed into a single matrix.
+    lu: Matrix<T, N, N>,
+    /// Row `i` of `P·A` is the row `perm[i]` of `A`.
+    perm: Vector<usize, N>,
+    /// Whether the permutation is odd.
+    odd: bool,
+    /// Whether some pivot is too small to be distinguished from zero.
+    singular: bool,
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Float,
+{
+    /// LU decomposition of the matrix.
+    ///
+    /// Pivot is considered to be zero if its absolute value doesn't exceed
+    /// the largest absolute value of the matrix elements multiplied by `N` and machine epsilon.
+    pub fn lu(&self) -> Lu<T, N> {
+        let scale = self.iter().fold(T::zero(), |a, x| a.max(x.abs()));
+        let threshold = scale * T::epsilon() * T::from(N).unwrap();
+
+        let mut lu = *self;
+        let mut perm = Vector::indices();
+        let mut odd = false;
+        let mut singular = false;
+
+        for k in 0..N {
+            let p = (k..N).fold(k, |p, i| {
+                if lu[(i, k)].abs() > lu[(p, k)].abs() {
+                    i
+                } else {
+                    p
+                }
+            });
+            if p != k {
+                lu.as_mut_array_of_vectors().swap(p, k);
+                perm.as_mut_array().swap(p, k);
+                odd = !odd;
+            }
+
+            let pivot = lu[(k, k)];
+            if pivot.abs() <= threshold {
+                singular = true;
+            }
+            if pivot.is_zero() {
+                // All elements below the pivot are zero too, so there is nothing to eliminate.
+                continue;
+            }
+            for i in (k + 1)..N {
+                let f = lu[(i, k)] / pivot;
+                lu[(i, k)] = f;
+                for j in (k + 1)..N {
+                    lu[(i, j)] = lu[(i, j)] - f * lu[(k, j)];
+                }
+            }
+        }
+
+        Lu {
+            lu,
+            perm,
+            odd,
+            singular,
+        }
+    }
+}
+
+impl<T, const N: usize> Lu<T, N>
+where
+    T: Float,
+{
+    /// Lower unitriangular matrix `L`.
+    pub fn l(&self) -> Matrix<T, N, N> {
+        Matrix::indices().map(|(i, j)| {
+            if i > j {
+                self.lu[(i, j)]
+            } else if i == j {
+                T::one()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    /// Upper triangular matrix `U`.
+    pub fn u(&self) -> Matrix<T, N, N> {
+        Matrix::indices().map(|(i, j)| if i <= j { self.lu[(i, j)] } else { T::zero() })
+    }
+
+    /// Permutation matrix `P`.
+    pub fn p(&self) -> Matrix<T, N, N> {
+        Matrix::indices().map(|(i, j)| {
+            if self.perm[i] == j {
+                T::one()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    /// Permutation of the rows, `i`-th row of `P·A` is `permutation()[i]`-th row of `A`.
+    pub fn permutation(&self) -> Vector<usize, N> {
+        self.perm
+    }
+
+    /// Whether the decomposed matrix is singular.
+    pub fn is_singular(&self) -> bool {
+        self.singular
+    }
+
+    /// Determinant of the decomposed matrix.
+    ///
+    /// Equals to exact zero if the matrix is singular.
+    pub fn det(&self) -> T {
+        if self.singular {
+            return T::zero();
+        }
+        let det = (0..N).fold(T::one(), |a, i| a * self.lu[(i, i)]);
+        if self.odd {
+            -det
+        } else {
+            det
+        }
+    }
+
+    /// Solve `A·x = b` for `x`.
+    ///
+    /// The matrix must not be singular, otherwise the result is meaningless.
+    /// Use [`Self::try_solve`] to check it.
+    pub fn solve(&self, b: Vector<T, N>) -> Vector<T, N> {
+        let mut x = self.perm.map(|i| b[i]);
+        for i in 0..N {
+            for j in 0..i {
+                x[i] = x[i] - self.lu[(i, j)] * x[j];
+            }
+        }
+        for i in (0..N).rev() {
+            for j in (i + 1)..N {
+                x[i] = x[i] - self.lu[(i, j)] * x[j];
+            }
+            x[i] = x[i] / self.lu[(i, i)];
+        }
+        x
+    }
+
+    /// Solve `A·X = B` for `X`.
+    pub fn solve_matrix<const K: usize>(&self, b: Matrix<T, N, K>) -> Matrix<T, N, K> {
+        Matrix::from_vector_of_vectors(
+            b.transpose()
+                .into_vector_of_vectors()
+                .map(|col| self.solve(col)),
+        )
+        .transpose()
+    }
+
+    /// Inverse of the decomposed matrix.
+    ///
+    /// The matrix must not be singular, otherwise the result is meaningless.
+    /// Use [`Self::try_inverse`] to check it.
+    pub fn inverse(&self) -> Matrix<T, N, N> {
+        self.solve_matrix(Matrix::one())
+    }
+
+    /// Solve `A·x = b` for `x`, or return `None` if the matrix is singular.
+    pub fn try_solve(&self, b: Vector<T, N>) -> Option<Vector<T, N>> {
+        if self.singular {
+            None
+        } else {
+            Some(self.solve(b))
+        }
+    }
+
+    /// Inverse of the decomposed matrix, or `None` if the matrix is singular.
+    pub fn try_inverse(&self) -> Option<Matrix<T, N, N>> {
+        if self.singular {
+            None
+        } else {
+            Some(self.inverse())
+        }
+    }
+}
+
+macro_rules! derive_det_strategy_float {
+    ($T:ident) => {
+        impl DetStrategy for $T {
+            fn det<const N: usize>(matrix: &Matrix<Self, N, N>) -> Self {
+                matrix.lu().det()
+            }
+            fn inv<const N: usize>(matrix: &Matrix<Self, N, N>) -> Matrix<Self, N, N> {
+                matrix.lu().inverse()
+            }
+        }
+    };
+}
+
+derive_det_strategy_float!(f32);
+derive_det_strategy_float!(f64);
@@ -1,20 +1,38 @@
 #[cfg(feature = "approx")]
 mod approx;
 mod base;
+mod block;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+mod cholesky;
 #[cfg(feature = "rand")]
 mod distr;
+mod eigen;
 mod format;
+mod lu;
+mod math;
 mod ops;
 mod product;
+mod qr;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "simd")]
+mod simd;
 mod square;
+mod svd;
 #[cfg(test)]
 mod tests;
 mod transpose;
-mod math;
 
 pub use base::*;
+pub use cholesky::*;
 #[cfg(feature = "rand")]
 pub use distr::*;
+pub use eigen::*;
+pub use lu::*;
+pub use qr::*;
+pub use square::*;
+pub use svd::*;
 
 pub type Matrix2x2<T> = Matrix<T, 2, 2>;
 pub type Matrix2x3<T> = Matrix<T, 2, 3>;
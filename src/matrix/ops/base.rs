@@ -4,7 +4,6 @@ use crate::{
 };
 use core::{
     cmp::PartialOrd,
-    iter::IntoIterator,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
 };
 use num_traits::{Float, Zero};
@@ -98,7 +97,7 @@ where
     T: AddAssign,
 {
     fn add_assign(&mut self, vec: Matrix<T, M, N>) {
-        self.iter_mut().zip(vec.into_iter()).for_each(|(s, x)| {
+        self.iter_mut().zip(vec).for_each(|(s, x)| {
             *s += x;
         })
     }
@@ -108,7 +107,7 @@ where
     T: SubAssign,
 {
     fn sub_assign(&mut self, vec: Matrix<T, M, N>) {
-        self.iter_mut().zip(vec.into_iter()).for_each(|(s, x)| {
+        self.iter_mut().zip(vec).for_each(|(s, x)| {
             *s -= x;
         })
     }
@@ -118,7 +117,7 @@ where
     T: MulAssign,
 {
     fn mul_assign(&mut self, vec: Matrix<T, M, N>) {
-        self.iter_mut().zip(vec.into_iter()).for_each(|(s, x)| {
+        self.iter_mut().zip(vec).for_each(|(s, x)| {
             *s *= x;
         })
     }
@@ -128,7 +127,7 @@ where
     T: DivAssign,
 {
     fn div_assign(&mut self, vec: Matrix<T, M, N>) {
-        self.iter_mut().zip(vec.into_iter()).for_each(|(s, x)| {
+        self.iter_mut().zip(vec).for_each(|(s, x)| {
             *s /= x;
         })
     }
@@ -138,7 +137,7 @@ where
     T: RemAssign,
 {
     fn rem_assign(&mut self, vec: Matrix<T, M, N>) {
-        self.iter_mut().zip(vec.into_iter()).for_each(|(s, x)| {
+        self.iter_mut().zip(vec).for_each(|(s, x)| {
             *s %= x;
         })
     }
@@ -47,9 +47,7 @@ where
     T: BitAndAssign,
 {
     fn bitand_assign(&mut self, other: Matrix<T, M, N>) {
-        self.iter_mut()
-            .zip(other.into_iter())
-            .for_each(|(s, y)| *s &= y)
+        self.iter_mut().zip(other).for_each(|(s, y)| *s &= y)
     }
 }
 impl<T, const M: usize, const N: usize> BitOrAssign for Matrix<T, M, N>
@@ -57,9 +55,7 @@ where
     T: BitOrAssign,
 {
     fn bitor_assign(&mut self, other: Matrix<T, M, N>) {
-        self.iter_mut()
-            .zip(other.into_iter())
-            .for_each(|(s, y)| *s |= y)
+        self.iter_mut().zip(other).for_each(|(s, y)| *s |= y)
     }
 }
 impl<T, const M: usize, const N: usize> BitXorAssign for Matrix<T, M, N>
@@ -67,9 +63,7 @@ where
     T: BitXorAssign,
 {
     fn bitxor_assign(&mut self, other: Matrix<T, M, N>) {
-        self.iter_mut()
-            .zip(other.into_iter())
-            .for_each(|(s, y)| *s ^= y)
+        self.iter_mut().zip(other).for_each(|(s, y)| *s ^= y)
     }
 }
 
@@ -0,0 +1,95 @@
+use crate::{traits::Dot, Matrix, Vector};
+use num_traits::{Float, One, Zero};
+
+/// QR decomposition of a matrix obtained by Householder reflections.
+///
+/// Represents the matrix `A` as `A = Q·R`, where `Q` is an orthogonal matrix
+/// and `R` is an upper trapezoidal matrix.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Qr<T, const M: usize, const N: usize> {
+    r: Matrix<T, M, N>,
+    /// Unit normals of the reflection hyperplanes, `Q = H_0·H_1·...·H_(N-1)` where `H_k = I - 2·v_k·v_kᵀ`.
+    refl: Vector<Vector<T, M>, N>,
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Float,
+{
+    /// QR decomposition of the matrix.
+    pub fn qr(&self) -> Qr<T, M, N> {
+        let two = T::one() + T::one();
+        let mut r = *self;
+        let mut refl = Vector::init(Vector::zero);
+
+        for k in 0..N.min(M) {
+            let mut v = Vector::indices().map(|i| if i >= k { r[(i, k)] } else { T::zero() });
+            let norm = v.length();
+            if norm == T::zero() {
+                continue;
+            }
+            let alpha = if v[k] > T::zero() { -norm } else { norm };
+            v[k] = v[k] - alpha;
+            let v = v.normalize();
+
+            for j in (k + 1)..N {
+                let s = v.dot(r.col(j)) * two;
+                for i in k..M {
+                    r[(i, j)] = r[(i, j)] - s * v[i];
+                }
+            }
+            r[(k, k)] = alpha;
+            for i in (k + 1)..M {
+                r[(i, k)] = T::zero();
+            }
+            refl[k] = v;
+        }
+
+        Qr { r, refl }
+    }
+}
+
+impl<T, const M: usize, const N: usize> Qr<T, M, N>
+where
+    T: Float,
+{
+    /// Multiply vector by `Qᵀ`.
+    pub fn apply_qt(&self, mut b: Vector<T, M>) -> Vector<T, M> {
+        let two = T::one() + T::one();
+        for v in self.refl.iter() {
+            b = b - *v * (v.dot(b) * two);
+        }
+        b
+    }
+
+    /// Orthogonal matrix `Q`.
+    pub fn q(&self) -> Matrix<T, M, M> {
+        Matrix::from_vector_of_vectors(
+            Matrix::<T, M, M>::one()
+                .into_vector_of_vectors()
+                .map(|e| self.apply_qt(e)),
+        )
+    }
+
+    /// Upper trapezoidal matrix `R`.
+    pub fn r(&self) -> Matrix<T, M, N> {
+        self.r
+    }
+
+    /// Find `x` that minimizes `|A·x - b|`.
+    ///
+    /// Requires `M >= N` and the columns of `A` to be linearly independent.
+    pub fn least_squares(&self, b: Vector<T, M>) -> Vector<T, N> {
+        assert!(M >= N);
+        let y = self.apply_qt(b);
+        let mut x = Vector::init(T::zero);
+        for i in (0..N).rev() {
+            let mut s = y[i];
+            for j in (i + 1)..N {
+                s = s - self.r[(i, j)] * x[j];
+            }
+            x[i] = s / self.r[(i, i)];
+        }
+        x
+    }
+}
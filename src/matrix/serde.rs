@@ -0,0 +1,31 @@
+use crate::{Matrix, Vector};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Matrix is serialized as a sequence of rows.
+impl<T: Serialize, const M: usize, const N: usize> Serialize for Matrix<T, M, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_vector_of_vectors().serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const M: usize, const N: usize> Deserialize<'de>
+    for Matrix<T, M, N>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vector::<Vector<T, N>, M>::deserialize(deserializer).map(Self::from_vector_of_vectors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Matrix;
+
+    #[test]
+    fn nested_rows() {
+        let m = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        let s = serde_json::to_string(&m).unwrap();
+        assert_eq!(s, "[[1,2,3],[4,5,6]]");
+        assert_eq!(serde_json::from_str::<Matrix<i32, 2, 3>>(&s).unwrap(), m);
+        assert!(serde_json::from_str::<Matrix<i32, 3, 2>>(&s).is_err());
+    }
+}
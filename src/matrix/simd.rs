@@ -0,0 +1,61 @@
+use crate::{Matrix, Vector};
+use wide::{f32x4, f64x4};
+
+macro_rules! impl_simd {
+    ($T:ident, $S:ident) => {
+        impl Matrix<$T, 4, 4> {
+            /// Matrix product using SIMD instructions.
+            ///
+            /// Each row of the result is a linear combination of the rows of `other`.
+            #[inline]
+            pub fn simd_dot(self, other: Self) -> Self {
+                let rows = other.into_array_of_vectors().map($S::from);
+                Matrix::from_array_of_vectors(self.into_array_of_vectors().map(|a| {
+                    let [a0, a1, a2, a3] = a.into_array();
+                    let r = rows[0] * $S::splat(a0);
+                    let r = rows[1].mul_add($S::splat(a1), r);
+                    let r = rows[2].mul_add($S::splat(a2), r);
+                    rows[3].mul_add($S::splat(a3), r).into()
+                }))
+            }
+            /// Matrix-vector product using SIMD instructions.
+            #[inline]
+            pub fn simd_dot_vector(self, vec: Vector<$T, 4>) -> Vector<$T, 4> {
+                let v = $S::from(vec);
+                Vector::from_array(
+                    self.into_array_of_vectors()
+                        .map(|a| ($S::from(a) * v).reduce_add()),
+                )
+            }
+        }
+    };
+}
+
+impl_simd!(f32, f32x4);
+impl_simd!(f64, f64x4);
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use crate::{distr::Normal, prelude::*, Matrix, Vector};
+    use approx::assert_abs_diff_eq;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+
+    #[test]
+    fn dot() {
+        let mut rng = XorShiftRng::seed_from_u64(0xBEEF);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let a: Matrix<f32, 4, 4> = rng.sample(&Normal);
+            let b: Matrix<f32, 4, 4> = rng.sample(&Normal);
+            let v: Vector<f32, 4> = rng.sample(&Normal);
+            assert_abs_diff_eq!(a.simd_dot(b), a.dot(b), epsilon = 1e-4);
+            assert_abs_diff_eq!(a.simd_dot_vector(v), a.dot(v), epsilon = 1e-4);
+
+            let a = a.map(|x| x as f64);
+            let b = b.map(|x| x as f64);
+            assert_abs_diff_eq!(a.simd_dot(b), a.dot(b), epsilon = 1e-12);
+        }
+    }
+}
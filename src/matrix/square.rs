@@ -1,6 +1,9 @@
-use crate::{Matrix, Vector};
+use crate::{
+    traits::{Epsilon, NormL1},
+    Matrix, Vector,
+};
 use core::ops::{Index, IndexMut, Neg};
-use num_traits::{Num, One, Zero, Inv};
+use num_traits::{Inv, Num, One, Zero};
 
 impl<T, const N: usize> Matrix<T, N, N>
 where
@@ -28,22 +31,6 @@ where
     }
 }
 
-// TODO: Implement when it will be possible to perform const generic arithmetics and specialization.
-/*
-impl<T, const N: usize> Matrix<T, N, N> where T: Copy, N > 1 {
-    /// Take submatrix from original matrix.
-    pub fn submatrix(&self, y: usize, x: usize) -> Matrix<T, N - 1, N - 1> {
-        Matrix::indices().map(|(i, j)| self[(i + (i >= y) as usize, j + (j >= x) as usize)].clone())
-    }
-}
-impl<T> Matrix<T, 1, 1> where T: Copy {
-    /// Take submatrix from original matrix.
-    pub fn submatrix(&self, y: usize, x: usize) -> T {
-        self[(0, 0)].clone()
-    }
-}
-*/
-
 struct IndexMask<const N: usize> {
     data: [bool; N],
 }
@@ -141,6 +128,55 @@ where
     }
 }
 
+/// Scalar that defines the way to find the determinant and the inverse of a square matrix.
+///
+/// Default implementation uses cofactor expansion, which is exact but takes `O(N!)` operations.
+/// Floating-point types override it with LU decomposition.
+/// Custom scalar types get cofactor expansion with an empty implementation.
+pub trait DetStrategy: Neg<Output = Self> + Num + Copy {
+    /// Determinant of the matrix.
+    fn det<const N: usize>(matrix: &Matrix<Self, N, N>) -> Self {
+        Determinator::new(matrix).det()
+    }
+    /// Inverse of the matrix.
+    fn inv<const N: usize>(matrix: &Matrix<Self, N, N>) -> Matrix<Self, N, N> {
+        matrix.adj() / Self::det(matrix)
+    }
+    /// Inverse of the matrix, or `None` if the matrix is singular.
+    ///
+    /// Matrix is considered to be singular if the norm of its determinant is inside epsilon area
+    /// relative to the product of L1 norms of its rows, which is the upper bound of the determinant norm.
+    /// For exact types it means that the determinant is zero.
+    fn try_inv<const N: usize>(matrix: &Matrix<Self, N, N>) -> Option<Matrix<Self, N, N>>
+    where
+        Self: NormL1,
+        <Self as NormL1>::Output: Epsilon + Num + Copy,
+    {
+        let scale =
+            matrix
+                .as_array_of_vectors()
+                .iter()
+                .fold(<Self as NormL1>::Output::one(), |s, row| {
+                    s * row
+                        .iter()
+                        .fold(<Self as NormL1>::Output::zero(), |a, x| a + x.norm_l1())
+                });
+        if Self::det(matrix).norm_l1().is_relative_epsilon(&scale) {
+            None
+        } else {
+            Some(Self::inv(matrix))
+        }
+    }
+}
+
+macro_rules! derive_det_strategy_exact {
+    ($($T:ident),*) => {
+        $(impl DetStrategy for $T {})*
+    };
+}
+
+derive_det_strategy_exact!(i8, i16, i32, i64, i128, isize);
+
 impl<T, const N: usize> Matrix<T, N, N>
 where
     T: Neg<Output = T> + Num + Copy,
@@ -151,29 +187,47 @@ where
         Determinator::new(self).cofactor((i, i), (j, j))
     }
 
-    /// Determinant of the matrix.
-    pub fn det(&self) -> T {
-        Determinator::new(self).det()
-    }
-
     /// Adjugate matrix.
     pub fn adj(&self) -> Self {
         Matrix::indices().map(|(i, j)| self.cofactor(j, i))
     }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: DetStrategy,
+{
+    /// Determinant of the matrix.
+    pub fn det(&self) -> T {
+        T::det(self)
+    }
 
     /// Inverse matrix.
     pub fn inv(&self) -> Self {
-        self.adj() / self.det()
+        T::inv(self)
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: DetStrategy + NormL1,
+    <T as NormL1>::Output: Epsilon + Num + Copy,
+{
+    /// Inverse matrix, or `None` if the matrix is singular.
+    ///
+    /// See [`DetStrategy::try_inv`] for the singularity criterion.
+    pub fn try_inv(&self) -> Option<Self> {
+        T::try_inv(self)
     }
 }
 
 impl<T, const N: usize> Inv for Matrix<T, N, N>
 where
-    T: Neg<Output = T> + Num + Copy,
+    T: DetStrategy,
 {
     type Output = Self;
 
     fn inv(self) -> Self::Output {
-        self.adj() / self.det()
+        T::inv(&self)
     }
 }
@@ -0,0 +1,222 @@
+use crate::{traits::Dot, Matrix, Vector};
+use num_traits::{Float, One, Zero};
+
+/// Maximum number of Jacobi sweeps.
+const MAX_SWEEPS: usize = 64;
+
+/// Singular value decomposition of a matrix.
+///
+/// Represents the matrix `A` as `A = U·Σ·Vᵀ`, where `U` and `V` are orthogonal matrices
+/// and `Σ` is a rectangular diagonal matrix of singular values.
+///
+/// There are always `N` singular values. If `M < N` then the last `N - M` of them are zero.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Svd<T, const M: usize, const N: usize> {
+    u: Matrix<T, M, M>,
+    values: Vector<T, N>,
+    vt: Matrix<T, N, N>,
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Float,
+{
+    /// Singular value decomposition of the matrix using one-sided Jacobi rotations.
+    ///
+    /// Singular values are sorted in descending order.
+    pub fn svd(&self) -> Svd<T, M, N> {
+        let mut w = *self;
+        let mut v = Matrix::<T, N, N>::one();
+
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 0..N {
+                for q in (p + 1)..N {
+                    let (wp, wq) = (w.col(p), w.col(q));
+                    let (alpha, beta, gamma) = (wp.square_length(), wq.square_length(), wp.dot(wq));
+                    if gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+
+                    let zeta = (beta - alpha) / (gamma + gamma);
+                    let t = zeta.signum() / (zeta.abs() + zeta.hypot(T::one()));
+                    let c = T::one() / t.hypot(T::one());
+                    let s = t * c;
+
+                    for k in 0..M {
+                        let (wkp, wkq) = (w[(k, p)], w[(k, q)]);
+                        w[(k, p)] = c * wkp - s * wkq;
+                        w[(k, q)] = s * wkp + c * wkq;
+                    }
+                    for k in 0..N {
+                        let (vkp, vkq) = (v[(k, p)], v[(k, q)]);
+                        v[(k, p)] = c * vkp - s * vkq;
+                        v[(k, q)] = s * vkp + c * vkq;
+                    }
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+
+        let norms = Vector::<usize, N>::indices().map(|j| w.col(j).length());
+        let mut order = Vector::<usize, N>::indices();
+        for i in 1..N {
+            let mut j = i;
+            while j > 0 && norms[order[j]] > norms[order[j - 1]] {
+                order.as_mut_array().swap(j, j - 1);
+                j -= 1;
+            }
+        }
+        let values = Vector::indices().map(|i| if i < M { norms[order[i]] } else { T::zero() });
+        let vt = Matrix::indices().map(|(i, j)| v[(j, order[i])]);
+
+        let max = if N > 0 { values[0] } else { T::zero() };
+        let threshold = max * T::epsilon() * T::from(M.max(N)).unwrap();
+        // Columns of `U` are stored as rows of `ut`.
+        let mut ut = Matrix::<T, M, M>::zero();
+        let mut filled = [false; M];
+        for i in 0..M.min(N) {
+            if values[i] > threshold {
+                *ut.row_mut(i) = w.col(order[i]) / values[i];
+                filled[i] = true;
+            }
+        }
+        complete_basis(&mut ut, &mut filled);
+
+        Svd {
+            u: ut.transpose(),
+            values,
+            vt,
+        }
+    }
+}
+
+/// Fill missing rows of the matrix to make it orthogonal.
+///
+/// Rows marked as filled are assumed to be orthonormal.
+fn complete_basis<T: Float, const M: usize>(basis: &mut Matrix<T, M, M>, filled: &mut [bool; M]) {
+    for i in 0..M {
+        if filled[i] {
+            continue;
+        }
+        let mut best = Vector::<T, M>::zero();
+        for k in 0..M {
+            let mut r = Vector::indices().map(|j| if j == k { T::one() } else { T::zero() });
+            // Orthogonalize twice for numerical stability.
+            for _ in 0..2 {
+                for (j, _) in filled.iter().enumerate().filter(|(_, f)| **f) {
+                    let b = basis.row(j);
+                    r = r - b * b.dot(r);
+                }
+            }
+            if r.square_length() > best.square_length() {
+                best = r;
+            }
+        }
+        *basis.row_mut(i) = best.normalize();
+        filled[i] = true;
+    }
+}
+
+impl<T, const M: usize, const N: usize> Svd<T, M, N>
+where
+    T: Float,
+{
+    /// Orthogonal matrix `U` which columns are the left singular vectors.
+    pub fn u(&self) -> Matrix<T, M, M> {
+        self.u
+    }
+
+    /// Singular values in descending order.
+    pub fn singular_values(&self) -> Vector<T, N> {
+        self.values
+    }
+
+    /// Rectangular diagonal matrix `Σ` of singular values.
+    pub fn sigma(&self) -> Matrix<T, M, N> {
+        Matrix::indices().map(|(i, j)| if i == j { self.values[j] } else { T::zero() })
+    }
+
+    /// Orthogonal matrix `Vᵀ` which rows are the right singular vectors.
+    pub fn vt(&self) -> Matrix<T, N, N> {
+        self.vt
+    }
+
+    /// Number of singular values that are greater than `eps`.
+    pub fn rank(&self, eps: T) -> usize {
+        self.values.iter().take(M).filter(|s| **s > eps).count()
+    }
+
+    /// Spectral norm, i.e. the largest singular value.
+    pub fn norm_2(&self) -> T {
+        if N > 0 {
+            self.values[0]
+        } else {
+            T::zero()
+        }
+    }
+
+    /// Ratio of the largest singular value to the smallest one.
+    ///
+    /// Equals to infinity for rank-deficient matrices.
+    pub fn condition_number(&self) -> T {
+        let k = M.min(N);
+        if k == 0 {
+            return T::one();
+        }
+        let min = self.values[k - 1];
+        if min == T::zero() {
+            T::infinity()
+        } else {
+            self.values[0] / min
+        }
+    }
+
+    /// Moore-Penrose pseudo-inverse of the decomposed matrix.
+    ///
+    /// Singular values that don't exceed the largest one multiplied by `max(M, N)`
+    /// and machine epsilon are treated as zeros.
+    pub fn pseudo_inverse(&self) -> Matrix<T, N, M> {
+        let threshold = self.norm_2() * T::epsilon() * T::from(M.max(N)).unwrap();
+        Matrix::indices().map(|(i, j)| {
+            (0..M.min(N)).fold(T::zero(), |a, k| {
+                let s = self.values[k];
+                if s > threshold {
+                    a + self.vt[(k, i)] * self.u[(j, k)] / s
+                } else {
+                    a
+                }
+            })
+        })
+    }
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N>
+where
+    T: Float,
+{
+    /// Matrix rank, i.e. number of singular values that are greater than `eps`.
+    pub fn rank(&self, eps: T) -> usize {
+        self.svd().rank(eps)
+    }
+
+    /// Spectral norm, i.e. the largest singular value.
+    ///
+    /// Unlike `norm_l2` which is the Frobenius norm.
+    pub fn norm_2(&self) -> T {
+        self.svd().norm_2()
+    }
+
+    /// Ratio of the largest singular value to the smallest one.
+    pub fn condition_number(&self) -> T {
+        self.svd().condition_number()
+    }
+
+    /// Moore-Penrose pseudo-inverse.
+    pub fn pseudo_inverse(&self) -> Matrix<T, N, M> {
+        self.svd().pseudo_inverse()
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{matrix::*, prelude::*, vector::*};
+use crate::{matrix::*, prelude::*, vector::*, Complex};
 
 macro_rules! mat_new_test {
     ($M:expr, $N:expr, $W:ident) => {
@@ -752,3 +752,356 @@ fn inv() {
     let dm = m - im;
     assert!(dm[(0, 0)].abs() + dm[(0, 1)].abs() + dm[(1, 0)].abs() + dm[(1, 1)].abs() < 1e-8);
 }
+
+#[test]
+fn det_lu() {
+    let m = Matrix3x3::<f64>::from([[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+    assert!((m.det() - 4.0).abs() < 1e-12);
+
+    let m = Matrix3x3::<f64>::from([[0.0, 1.0, 2.0], [1.0, 0.0, 3.0], [4.0, -3.0, 8.0]]);
+    assert!((m.det() - m.map(|x| x as i32).det() as f64).abs() < 1e-12);
+}
+
+#[test]
+fn det_i128() {
+    let m = Matrix2x2::<i128>::from([[2, 1], [3, 2]]);
+    assert_eq!(m.det(), 1);
+    assert_eq!(m.inv(), Matrix2x2::from([[2, -1], [-3, 2]]));
+}
+
+#[test]
+fn lu() {
+    let m = Matrix4x4::<f64>::from([
+        [1.0, 2.0, 0.0, -1.0],
+        [3.0, -1.0, 2.0, 0.0],
+        [0.0, 4.0, 1.0, 2.0],
+        [-2.0, 0.0, 3.0, 1.0],
+    ]);
+    let lu = m.lu();
+    assert!(!lu.is_singular());
+    let dm = lu.p().dot(m) - lu.l().dot(lu.u());
+    assert!(dm.norm_l1() < 1e-12);
+    for i in 0..4 {
+        assert_eq!(lu.l()[(i, i)], 1.0);
+        for j in (i + 1)..4 {
+            assert_eq!(lu.l()[(i, j)], 0.0);
+            assert_eq!(lu.u()[(j, i)], 0.0);
+        }
+    }
+    assert!((lu.det() - m.map(|x| x as i32).det() as f64).abs() < 1e-10);
+}
+
+#[test]
+fn lu_solve() {
+    let m = Matrix3x3::<f64>::from([[0.0, 2.0, 1.0], [1.0, -1.0, 0.0], [3.0, 0.0, -2.0]]);
+    let x = Vector3::<f64>::from([1.0, -2.0, 3.0]);
+    let lu = m.lu();
+    assert!((lu.solve(m.dot(x)) - x).norm_l1() < 1e-12);
+
+    let xs = Matrix3x2::<f64>::from([[1.0, 0.5], [-2.0, 4.0], [3.0, -1.0]]);
+    assert!((lu.solve_matrix(m.dot(xs)) - xs).norm_l1() < 1e-12);
+
+    assert!((lu.inverse().dot(m) - Matrix3x3::one()).norm_l1() < 1e-12);
+    assert_eq!(lu.try_solve(m.dot(x)), Some(lu.solve(m.dot(x))));
+    assert_eq!(lu.try_inverse(), Some(lu.inverse()));
+}
+
+#[test]
+fn lu_singular() {
+    let m = Matrix3x3::<f64>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    let lu = m.lu();
+    assert!(lu.is_singular());
+    assert!((lu.p().dot(m) - lu.l().dot(lu.u())).norm_l1() < 1e-12);
+    assert_eq!(lu.try_solve(Vector3::fill(1.0)), None);
+    assert_eq!(lu.try_inverse(), None);
+    assert_eq!(m.det(), 0.0);
+
+    let m = Matrix3x3::<f64>::from([[0.0, 1.0, 2.0], [0.0, 3.0, 4.0], [0.0, 5.0, 7.0]]);
+    let lu = m.lu();
+    assert!(lu.is_singular());
+    assert_eq!(lu.p().dot(m), lu.l().dot(lu.u()));
+
+    assert!(Matrix4x4::<f64>::zero().lu().is_singular());
+    assert!(!Matrix4x4::<f64>::one().lu().is_singular());
+}
+
+#[test]
+fn inv_large() {
+    let m: Matrix<f64, 8, 8> = Matrix::indices().map(|(i, j)| {
+        if i == j {
+            4.0
+        } else {
+            1.0 / (1.0 + i as f64 + 2.0 * j as f64)
+        }
+    });
+    assert!((m.inv().dot(m) - Matrix::one()).norm_l1() < 1e-12);
+    assert!((m.dot(m.inv()) - Matrix::one()).norm_l1() < 1e-12);
+}
+
+#[test]
+fn try_inv() {
+    let m = Matrix2x2::<i32>::from([[2, 1], [3, 2]]);
+    assert_eq!(m.try_inv(), Some(Matrix2x2::from([[2, -1], [-3, 2]])));
+    assert_eq!(Matrix2x2::<i32>::from([[2, 1], [4, 2]]).try_inv(), None);
+
+    let m = Matrix3x3::<f64>::from([[2.0, 0.0, 1.0], [1.0, 1.0, 0.0], [0.0, 3.0, 1.0]]);
+    assert!((m.try_inv().unwrap().dot(m) - Matrix3x3::one()).norm_l1() < 1e-12);
+    let m = Matrix3x3::<f64>::from([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 5.0]]);
+    assert_eq!(m.try_inv(), None);
+    assert_eq!((m * 1e-20).try_inv(), None);
+    assert!((Matrix3x3::<f64>::one() * 1e-20).try_inv().is_some());
+
+    let m = Matrix2x2::from([
+        [Complex::new(1e-20, 0.0), Complex::new(0.0, 0.0)],
+        [Complex::new(0.0, 0.0), Complex::new(0.0, 1e-20)],
+    ]);
+    assert!(m.try_inv().is_some());
+    let m = Matrix2x2::from([[m[(0, 0)], m[(1, 1)]], [m[(0, 0)] * 2.0, m[(1, 1)] * 2.0]]);
+    assert_eq!(m.try_inv(), None);
+}
+
+#[test]
+fn qr() {
+    let m = Matrix4x3::<f64>::from([
+        [1.0, 2.0, 0.0],
+        [3.0, -1.0, 2.0],
+        [0.0, 4.0, 1.0],
+        [-2.0, 0.0, 3.0],
+    ]);
+    let qr = m.qr();
+    let (q, r) = (qr.q(), qr.r());
+    assert!((q.dot(r) - m).norm_l1() < 1e-12);
+    assert!((q.transpose().dot(q) - Matrix4x4::one()).norm_l1() < 1e-12);
+    for j in 0..3 {
+        for i in (j + 1)..4 {
+            assert_eq!(r[(i, j)], 0.0);
+        }
+    }
+
+    let m = Matrix3x3::<f64>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    let qr = m.qr();
+    assert!((qr.q().dot(qr.r()) - m).norm_l1() < 1e-12);
+
+    let qr = Matrix3x3::<f64>::zero().qr();
+    assert_eq!(qr.q(), Matrix3x3::one());
+}
+
+#[test]
+fn least_squares() {
+    // Line through points exactly.
+    let a = Matrix::<f64, 5, 2>::from([[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0], [1.0, 4.0]]);
+    let b = Vector::<f64, 5>::from([1.0, 3.0, 5.0, 7.0, 9.0]);
+    let x = a.qr().least_squares(b);
+    assert!((x - Vector2::from([1.0, 2.0])).norm_l1() < 1e-12);
+
+    // Compare with normal equations.
+    let a: Matrix<f64, 6, 3> =
+        Matrix::indices().map(|(i, j)| ((i * 7 + j * 3) % 5) as f64 - 2.0 + j as f64);
+    let b = Vector::<f64, 6>::from([1.0, -2.0, 0.5, 3.0, 0.0, 1.5]);
+    let x = a.qr().least_squares(b);
+    let at = a.transpose();
+    let y = at.dot(a).inv().dot(at.dot(b));
+    assert!((x - y).norm_l1() < 1e-10);
+}
+
+#[test]
+fn symmetric_eigen() {
+    let m = Matrix2x2::<f64>::from([[2.0, 1.0], [1.0, 2.0]]);
+    let eig = m.symmetric_eigen();
+    assert!((eig.eigenvalues() - Vector2::from([1.0, 3.0])).norm_l1() < 1e-12);
+    let v = eig.eigenvectors().col(1);
+    assert!((v * v.x().signum() - Vector2::fill(0.5f64.sqrt())).norm_l1() < 1e-12);
+
+    let m = Matrix3x3::<f64>::from([[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+    let eig = m.symmetric_eigen();
+    let s = 2.0f64.sqrt();
+    assert!((eig.eigenvalues() - Vector3::from([2.0 - s, 2.0, 2.0 + s])).norm_l1() < 1e-12);
+    assert!((eig.eigenvectors().det() - 1.0).abs() < 1e-12);
+    for i in 0..3 {
+        let v = eig.eigenvectors().col(i);
+        assert!((m.dot(v) - v * eig.eigenvalues()[i]).norm_l1() < 1e-12);
+    }
+}
+
+#[test]
+fn symmetric_eigen_large() {
+    let a: Matrix<f64, 8, 8> = Matrix::indices().map(|(i, j)| ((i * 5 + j * 3) % 7) as f64 - 3.0);
+    let m = a.transpose().dot(a) - a - a.transpose();
+    let eig = m.symmetric_eigen();
+    let (d, v) = (eig.eigenvalues(), eig.eigenvectors());
+    assert!((eig.recompose() - m).norm_l1() < 1e-10);
+    assert!((v.transpose().dot(v) - Matrix::one()).norm_l1() < 1e-12);
+    for i in 1..8 {
+        assert!(d[i - 1] <= d[i]);
+    }
+
+    let eig = Matrix4x4::<f64>::one().symmetric_eigen();
+    assert_eq!(eig.eigenvalues(), Vector4::fill(1.0));
+    assert_eq!(eig.eigenvectors(), Matrix4x4::one());
+}
+
+macro_rules! mat_svd_test {
+    ($M:expr, $N:expr) => {
+        let m: Matrix<f64, $M, $N> = Matrix::indices()
+            .map(|(i, j)| ((i * 5 + j * 3 + 1) % 7) as f64 - 3.0 + if i == j { 0.5 } else { 0.0 });
+        let svd = m.svd();
+        let (u, vt) = (svd.u(), svd.vt());
+        assert!((u.dot(svd.sigma()).dot(vt) - m).norm_l1() < 1e-10);
+        assert!((u.transpose().dot(u) - Matrix::one()).norm_l1() < 1e-12);
+        assert!((vt.transpose().dot(vt) - Matrix::one()).norm_l1() < 1e-12);
+        let s = svd.singular_values();
+        for i in 1..$N {
+            assert!(s[i - 1] >= s[i]);
+        }
+
+        let p = m.pseudo_inverse();
+        assert!((m.dot(p).dot(m) - m).norm_l1() < 1e-10);
+        assert!((p.dot(m).dot(p) - p).norm_l1() < 1e-10);
+    };
+}
+#[test]
+fn svd() {
+    mat_svd_test!(2, 2);
+    mat_svd_test!(3, 3);
+    mat_svd_test!(4, 2);
+    mat_svd_test!(2, 4);
+    mat_svd_test!(6, 3);
+    mat_svd_test!(3, 6);
+    mat_svd_test!(8, 8);
+}
+
+#[test]
+fn svd_helpers() {
+    let m = Matrix2x2::<f64>::from([[3.0, 0.0], [0.0, -2.0]]);
+    assert!((m.norm_2() - 3.0).abs() < 1e-12);
+    assert!((m.condition_number() - 1.5).abs() < 1e-12);
+    assert_eq!(m.rank(1e-12), 2);
+
+    let m = Matrix3x3::<f64>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    assert_eq!(m.rank(1e-10), 2);
+    assert_eq!(m.svd().u().det().abs().round(), 1.0);
+    assert!(m.condition_number() > 1e15);
+    assert_eq!(Matrix3x4::<f64>::zero().rank(1e-10), 0);
+
+    let m = Matrix3x3::<f64>::from([[2.0, 0.0, 1.0], [1.0, 1.0, 0.0], [0.0, 3.0, 1.0]]);
+    assert!((m.pseudo_inverse() - m.inv()).norm_l1() < 1e-12);
+    assert!(m.norm_2() <= m.norm_l2());
+}
+
+#[test]
+fn cholesky() {
+    let b: Matrix<f64, 6, 6> =
+        Matrix::indices().map(|(i, j)| ((i * 5 + j * 3 + 1) % 7) as f64 - 3.0);
+    let a = b.dot(b.transpose()) + Matrix::one();
+    let ch = a.cholesky().unwrap();
+    let l = ch.l();
+    assert!((l.dot(l.transpose()) - a).norm_l1() < 1e-10);
+    assert!((ch.det() - a.lu().det()).abs() < 1e-8 * a.lu().det().abs());
+
+    let x = Vector::from([1.0, -2.0, 3.0, 0.5, 0.0, -1.0]);
+    assert!((ch.solve(a.dot(x)) - x).norm_l1() < 1e-10);
+
+    let inv = ch.inverse();
+    assert_eq!(inv, inv.transpose());
+    assert!((inv.dot(a) - Matrix::one()).norm_l1() < 1e-10);
+
+    assert!(Matrix2x2::<f64>::from([[1.0, 2.0], [2.0, 1.0]])
+        .cholesky()
+        .is_none());
+    assert!(Matrix2x2::<f64>::from([[1.0, 1.0], [1.0, 1.0]])
+        .cholesky()
+        .is_none());
+}
+
+#[test]
+fn ldlt() {
+    let b: Matrix<f64, 4, 2> = Matrix::from([[1.0, 0.0], [2.0, 1.0], [0.0, -1.0], [1.0, 1.0]]);
+    let a = b.dot(b.transpose());
+    assert!(a.cholesky().is_none());
+    let ldlt = a.ldlt().unwrap();
+    assert!(ldlt.is_singular());
+    assert!(ldlt.is_positive_semidefinite());
+    let (l, d) = (ldlt.l(), ldlt.d());
+    assert!((l.dot(Matrix::diagonal(d)).dot(l.transpose()) - a).norm_l1() < 1e-12);
+
+    let a = Matrix3x3::<f64>::from([[4.0, 2.0, -2.0], [2.0, -3.0, 1.0], [-2.0, 1.0, 5.0]]);
+    let ldlt = a.ldlt().unwrap();
+    assert!(!ldlt.is_positive_semidefinite());
+    assert!((ldlt.det() - a.det()).abs() < 1e-10);
+    assert!((ldlt.inverse() - a.inv()).norm_l1() < 1e-12);
+}
+
+#[test]
+fn ldlt_zero_pivot() {
+    let a = Matrix2x2::<f64>::from([[0.0, 1.0], [1.0, 0.0]]);
+    assert!(a.ldlt().is_none());
+
+    let a = Matrix3x3::<f64>::from([[0.0, 0.0, 0.0], [0.0, 2.0, 1.0], [0.0, 1.0, -1.0]]);
+    let ldlt = a.ldlt().unwrap();
+    let (l, d) = (ldlt.l(), ldlt.d());
+    assert!((l.dot(Matrix::diagonal(d)).dot(l.transpose()) - a).norm_l1() < 1e-12);
+    assert_eq!(ldlt.det(), a.det());
+}
+
+#[test]
+fn minor() {
+    let m = Matrix3x4::from([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]);
+    assert_eq!(m.minor(1, 2), Matrix2x3::from([[1, 2, 4], [9, 10, 12]]));
+    assert_eq!(m.minor(0, 0), Matrix2x3::from([[6, 7, 8], [10, 11, 12]]));
+    assert_eq!(m.minor(2, 3), Matrix2x3::from([[1, 2, 3], [5, 6, 7]]));
+}
+
+#[test]
+#[should_panic]
+fn minor_wrong_size() {
+    let _: Matrix2x2<i32> = Matrix3x4::fill(0).minor(0, 0);
+}
+
+#[test]
+fn block() {
+    let m = Matrix3x4::from([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]);
+    assert_eq!(m.block(1, 1), Matrix2x2::from([[6, 7], [10, 11]]));
+    assert_eq!(m.block::<3, 4>(0, 0), m);
+    assert_eq!(
+        m.fixed_rows(1),
+        Matrix2x4::from([[5, 6, 7, 8], [9, 10, 11, 12]])
+    );
+    assert_eq!(m.fixed_columns(3), Matrix::from([[4], [8], [12]]));
+
+    let mut h = Matrix4x4::one();
+    h.set_block(0, 0, Matrix3x3::from([[0, -1, 0], [1, 0, 0], [0, 0, 1]]));
+    h.set_block(0, 3, Matrix::from([[1], [2], [3]]));
+    assert_eq!(
+        h,
+        Matrix4x4::from([[0, -1, 0, 1], [1, 0, 0, 2], [0, 0, 1, 3], [0, 0, 0, 1]])
+    );
+    assert_eq!(
+        h.block::<3, 3>(0, 0),
+        Matrix3x3::from([[0, -1, 0], [1, 0, 0], [0, 0, 1]])
+    );
+}
+
+#[test]
+#[should_panic]
+fn block_out_of_bounds() {
+    let _: Matrix2x2<i32> = Matrix3x3::fill(0).block(2, 0);
+}
+
+#[test]
+fn slice_from_flat() {
+    let a = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+    let ms = <Matrix2x3<i32>>::slice_from_flat(&a).unwrap();
+    assert_eq!(
+        ms,
+        &[
+            Matrix::from([[0, 1, 2], [3, 4, 5]]),
+            Matrix::from([[6, 7, 8], [9, 10, 11]])
+        ]
+    );
+    assert_eq!(<Matrix2x3<i32>>::flatten(ms), &a[..]);
+    assert!(<Matrix2x2<i32>>::slice_from_flat(&a[..6]).is_err());
+
+    let mut b = [0; 8];
+    <Matrix2x2<i32>>::slice_from_flat_mut(&mut b).unwrap()[1] = Matrix::one();
+    assert_eq!(b, [0, 0, 0, 0, 1, 0, 0, 1]);
+}
@@ -58,9 +58,16 @@ pub trait Conj {
 pub trait Epsilon {
     /// Check that value is inside epsilon area.
     fn is_epsilon(&self) -> bool;
+    /// Check that value is inside epsilon area scaled by the magnitude of `scale`.
+    ///
+    /// By default the scale is ignored, which is suitable for exact types.
+    fn is_relative_epsilon(&self, scale: &Self) -> bool {
+        let _ = scale;
+        self.is_epsilon()
+    }
 }
 
-/// Make new dimensions by copying values along axes. 
+/// Make new dimensions by copying values along axes.
 pub trait Broadcast<V> {
     /// Copy values along axes to get `V`.
     fn broadcast(self) -> V;
@@ -77,10 +84,22 @@ macro_rules! derive_primitive_base {
     };
 }
 
-macro_rules! derive_primitive_unsigned {
+macro_rules! derive_primitive_integer {
     ($T:ident) => {
         derive_primitive_base!($T);
 
+        impl Epsilon for $T {
+            fn is_epsilon(&self) -> bool {
+                *self == 0
+            }
+        }
+    };
+}
+
+macro_rules! derive_primitive_unsigned {
+    ($T:ident) => {
+        derive_primitive_integer!($T);
+
         impl NormL1 for $T {
             type Output = Self;
             fn norm_l1(self) -> Self {
@@ -107,7 +126,7 @@ macro_rules! derive_primitive_unsigned {
 
 macro_rules! derive_primitive_signed {
     ($T:ident) => {
-        derive_primitive_base!($T);
+        derive_primitive_integer!($T);
 
         impl NormL1 for $T {
             type Output = Self;
@@ -167,6 +186,9 @@ macro_rules! derive_primitive_float {
             fn is_epsilon(&self) -> bool {
                 self.abs() <= Self::EPSILON
             }
+            fn is_relative_epsilon(&self, scale: &Self) -> bool {
+                self.abs() <= Self::EPSILON * scale.abs()
+            }
         }
     };
 }
@@ -1,6 +1,15 @@
 #[cfg(feature = "rand")]
 use crate::distr::{Invertible, Normal};
-use crate::{Vector, transform::{Chain, Linear, Shift}};
+use crate::{
+    matrix::DetStrategy,
+    traits::{Epsilon, NormL1},
+    transform::{
+        homogeneous::{join_homogeneous, split_homogeneous},
+        Chain, Linear, Reorder, Shift,
+    },
+    Matrix, Transform, Vector,
+};
+use num_traits::{Num, One, Zero};
 #[cfg(feature = "rand")]
 use rand_::{distributions::Distribution, Rng};
 
@@ -26,6 +35,37 @@ where
     }
 }
 
+impl<T, const N: usize> Affine<T, N>
+where
+    T: Zero + One + PartialEq + Copy,
+{
+    /// Homogeneous `L`x`L` matrix of the transformation, where `L = N + 1`.
+    pub fn to_homogeneous<const L: usize>(&self) -> Matrix<T, L, L> {
+        join_homogeneous(self.linear().into_matrix(), self.shift().into_vector())
+    }
+
+    /// Create transformation from homogeneous `L`x`L` matrix, where `L = N + 1`.
+    ///
+    /// Returns `None` if the last row of the matrix isn't `[0, ..., 0, 1]`.
+    pub fn from_homogeneous<const L: usize>(mat: Matrix<T, L, L>) -> Option<Self> {
+        split_homogeneous(mat).map(|(lin, shift)| Self::new(Shift::from(shift), Linear::from(lin)))
+    }
+}
+
+impl<T, const N: usize> Affine<T, N>
+where
+    T: DetStrategy + NormL1,
+    <T as NormL1>::Output: Epsilon + Num + Copy,
+{
+    /// Inverse transformation, or `None` if the linear component is degenerate.
+    pub fn try_inv(self) -> Option<Self> {
+        let (shift, linear) = self.split();
+        linear
+            .try_inv()
+            .map(|linear| linear.reorder(shift.inv()).into())
+    }
+}
+
 #[cfg(feature = "rand")]
 impl<T, const N: usize> Distribution<Affine<T, N>> for Normal
 where
@@ -98,6 +138,42 @@ mod tests {
                 assert_abs_diff_eq!(m1.apply(m0.apply(v)), m1.chain(m0).apply(v));
             };
         }
+        macro_rules! try_inverse_test {
+            ($X:ident, $M:ident, $V:ident) => {
+                let m = $X::new($V::fill(1.0).into(), ($M::fill(1.0) + $M::one()).into());
+                assert_eq!(m.try_inv(), Some(m.inv()));
+                let m = $X::new($V::fill(1.0).into(), $M::fill(1.0).into());
+                assert!(m.try_inv().is_none());
+            };
+        }
+        #[test]
+        fn try_inverse() {
+            try_inverse_test!(Affine2, Matrix2x2, Vector2);
+            try_inverse_test!(Affine3, Matrix3x3, Vector3);
+            try_inverse_test!(Affine4, Matrix4x4, Vector4);
+        }
+
+        macro_rules! homogeneous_test {
+            ($X:ident, $M:ident, $V:ident, $H:ident, $N:expr) => {
+                let m = $X::new(
+                    $V::indices().map(|i| i as f64).into(),
+                    ($M::fill(1.0) + $M::one()).into(),
+                );
+                let mut h: $H<f64> = m.to_homogeneous();
+                assert_eq!(h.block(0, 0), m.linear().into_matrix());
+                assert_eq!($X::from_homogeneous(h), Some(m));
+                h[(0, 0)] = 0.0;
+                assert_ne!($X::from_homogeneous(h), Some(m));
+                h[($N, 0)] = 1.0;
+                assert!($X::from_homogeneous(h).is_none());
+            };
+        }
+        #[test]
+        fn homogeneous() {
+            homogeneous_test!(Affine2, Matrix2x2, Vector2, Matrix3x3, 2);
+            homogeneous_test!(Affine3, Matrix3x3, Vector3, Matrix4x4, 3);
+        }
+
         #[test]
         fn chain() {
             chain_test!(Affine2, Matrix2x2, Vector2);
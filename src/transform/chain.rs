@@ -1,4 +1,9 @@
-use crate::{Transform, transform::Directional, traits::Normalize};
+use crate::{
+    batch::{Batch, PointBatch},
+    traits::Normalize,
+    transform::Directional,
+    Transform,
+};
 #[cfg(feature = "approx")]
 use approx::{abs_diff_eq, AbsDiffEq};
 use core::marker::PhantomData;
@@ -7,9 +12,11 @@ use core::marker::PhantomData;
 ///
 /// Transformations are applied in the following order: `A(B(x))`.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Chain<A, B, T> {
     outer: A,
     inner: B,
+    #[cfg_attr(feature = "serde", serde(skip))]
     phantom: PhantomData<T>,
 }
 
@@ -80,6 +87,13 @@ where
     fn apply(&self, pos: T) -> T {
         self.outer.apply(self.inner.apply(pos))
     }
+    fn batched(&self) -> impl Fn(PointBatch<T>) -> PointBatch<T>
+    where
+        T: Batch,
+    {
+        let (inner, outer) = (self.inner.batched(), self.outer.batched());
+        move |pos| outer(inner(pos))
+    }
     fn deriv(&self, pos: T, dir: T) -> T {
         self.outer
             .deriv(self.inner.apply(pos), self.inner.deriv(pos, dir))
@@ -95,13 +109,15 @@ where
     A: Directional<T>,
     B: Directional<T>,
     Self: Transform<T>,
-    T: Normalize + Copy
+    T: Normalize + Copy,
 {
     fn apply_dir(&self, pos: T, dir: T) -> T {
-        self.outer.apply_dir(self.inner.apply(pos), self.inner.apply_dir(pos, dir))
+        self.outer
+            .apply_dir(self.inner.apply(pos), self.inner.apply_dir(pos, dir))
     }
     fn apply_normal(&self, pos: T, normal: T) -> T {
-        self.outer.apply_normal(self.inner.apply(pos), self.inner.apply_normal(pos, normal))
+        self.outer
+            .apply_normal(self.inner.apply(pos), self.inner.apply_normal(pos, normal))
     }
 }
 
@@ -121,3 +137,29 @@ where
             && abs_diff_eq!(self.inner, other.inner, epsilon = epsilon)
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use crate::{
+        transform::{Affine3, Linear, Shift},
+        Matrix, Vector,
+    };
+
+    #[test]
+    fn serde() {
+        let affine = Affine3::new(
+            Shift::from(Vector::from([1.0, 2.0, 3.0])),
+            Linear::from(Matrix::from([
+                [1.0, 0.0, 0.0],
+                [0.0, 0.0, -1.0],
+                [0.0, 1.0, 0.0],
+            ])),
+        );
+        let s = serde_json::to_string(&affine).unwrap();
+        assert_eq!(
+            s,
+            r#"{"outer":[1.0,2.0,3.0],"inner":[[1.0,0.0,0.0],[0.0,0.0,-1.0],[0.0,1.0,0.0]]}"#
+        );
+        assert_eq!(serde_json::from_str::<Affine3<f64>>(&s).unwrap(), affine);
+    }
+}
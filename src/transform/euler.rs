@@ -0,0 +1,287 @@
+use crate::{transform::Rotation3, Transform, Vector};
+use num_traits::{Float, NumCast};
+
+/// Sequence of axes of Euler angles.
+///
+/// Includes six Tait-Bryan sequences (all axes are different) and six proper Euler sequences
+/// (first and last axes are the same).
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EulerAxes {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+impl EulerAxes {
+    /// Indices of the axes.
+    pub fn indices(self) -> (usize, usize, usize) {
+        match self {
+            EulerAxes::XYZ => (0, 1, 2),
+            EulerAxes::XZY => (0, 2, 1),
+            EulerAxes::YXZ => (1, 0, 2),
+            EulerAxes::YZX => (1, 2, 0),
+            EulerAxes::ZXY => (2, 0, 1),
+            EulerAxes::ZYX => (2, 1, 0),
+            EulerAxes::XYX => (0, 1, 0),
+            EulerAxes::XZX => (0, 2, 0),
+            EulerAxes::YXY => (1, 0, 1),
+            EulerAxes::YZY => (1, 2, 1),
+            EulerAxes::ZXZ => (2, 0, 2),
+            EulerAxes::ZYZ => (2, 1, 2),
+        }
+    }
+}
+
+/// Order of Euler angles.
+///
+/// For intrinsic sequence each rotation is performed around the axis of already rotated frame,
+/// so angles `(a, b, c)` of `Intrinsic(XYZ)` give rotation `Rx(a)·Ry(b)·Rz(c)`.
+///
+/// For extrinsic sequence each rotation is performed around the axis of fixed frame,
+/// so angles `(a, b, c)` of `Extrinsic(XYZ)` give rotation `Rz(c)·Ry(b)·Rx(a)`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EulerOrder {
+    Intrinsic(EulerAxes),
+    Extrinsic(EulerAxes),
+}
+
+fn basis<T: Float>(i: usize) -> Vector<T, 3> {
+    Vector::indices().map(|j| if i == j { T::one() } else { T::zero() })
+}
+
+/// Parity of permutation of three different axes, `1` for even and `-1` for odd one.
+fn parity<T: Float>(i: usize, j: usize) -> T {
+    if (j + 3 - i) % 3 == 1 {
+        T::one()
+    } else {
+        -T::one()
+    }
+}
+
+impl<T> Rotation3<T>
+where
+    T: Float + NumCast,
+{
+    fn from_intrinsic((i, j, k): (usize, usize, usize), a: T, b: T, c: T) -> Self {
+        Self::new(basis(i), a)
+            .chain(Self::new(basis(j), b))
+            .chain(Self::new(basis(k), c))
+    }
+
+    fn to_intrinsic(self, (i, j, k): (usize, usize, usize)) -> (T, T, T) {
+        let m = self.to_linear().into_matrix();
+        // Angle cosine (or sine for Tait-Bryan angles) below this value is considered to be zero.
+        // In that case separate angles can't be determined with precision better than `eps / cos`.
+        let threshold = T::epsilon().sqrt();
+        if i != k {
+            let s = parity::<T>(i, j);
+            let cos = m[(i, i)].hypot(m[(i, j)]);
+            let b = (s * m[(i, k)]).atan2(cos);
+            if cos > threshold {
+                let a = (-s * m[(j, k)]).atan2(m[(k, k)]);
+                let c = (-s * m[(i, j)]).atan2(m[(i, i)]);
+                (a, b, c)
+            } else {
+                let a = (s * m[(k, j)]).atan2(m[(j, j)]);
+                (a, b, T::zero())
+            }
+        } else {
+            let k = 3 - i - j;
+            let s = parity::<T>(i, j);
+            let sin = m[(i, j)].hypot(m[(i, k)]);
+            let b = sin.atan2(m[(i, i)]);
+            if sin > threshold {
+                let a = m[(j, i)].atan2(-s * m[(k, i)]);
+                let c = m[(i, j)].atan2(s * m[(i, k)]);
+                (a, b, c)
+            } else {
+                let a = (s * m[(k, j)]).atan2(m[(j, j)]);
+                (a, b, T::zero())
+            }
+        }
+    }
+
+    /// Create rotation from Euler angles `(a, b, c)` given in specified order.
+    pub fn from_euler(order: EulerOrder, a: T, b: T, c: T) -> Self {
+        match order {
+            EulerOrder::Intrinsic(axes) => Self::from_intrinsic(axes.indices(), a, b, c),
+            EulerOrder::Extrinsic(axes) => {
+                let (i, j, k) = axes.indices();
+                Self::from_intrinsic((k, j, i), c, b, a)
+            }
+        }
+    }
+
+    /// Find Euler angles `(a, b, c)` of the rotation in specified order.
+    ///
+    /// Angle `b` is in `[-π/2, π/2]` for Tait-Bryan angles and in `[0, π]` for proper Euler angles,
+    /// other angles are in `[-π, π]`.
+    ///
+    /// In case of gimbal lock the angle of the rotation that is applied first
+    /// (i.e. `c` for intrinsic and `a` for extrinsic order) is set to zero.
+    pub fn to_euler(self, order: EulerOrder) -> (T, T, T) {
+        match order {
+            EulerOrder::Intrinsic(axes) => self.to_intrinsic(axes.indices()),
+            EulerOrder::Extrinsic(axes) => {
+                let (i, j, k) = axes.indices();
+                let (c, b, a) = self.to_intrinsic((k, j, i));
+                (a, b, c)
+            }
+        }
+    }
+
+    /// Create rotation from aircraft principal axes angles.
+    ///
+    /// Rotation is performed in intrinsic `ZYX` order:
+    /// yaw around `z`-axis, then pitch around new `y`-axis and then roll around new `x`-axis.
+    pub fn from_yaw_pitch_roll(yaw: T, pitch: T, roll: T) -> Self {
+        Self::from_euler(EulerOrder::Intrinsic(EulerAxes::ZYX), yaw, pitch, roll)
+    }
+
+    /// Find aircraft principal axes angles `(yaw, pitch, roll)` of the rotation.
+    ///
+    /// See [`Self::from_yaw_pitch_roll`] for convention.
+    pub fn to_yaw_pitch_roll(self) -> (T, T, T) {
+        self.to_euler(EulerOrder::Intrinsic(EulerAxes::ZYX))
+    }
+}
+
+#[cfg(all(test, feature = "approx"))]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    const EPS: f64 = 1e-12;
+
+    const ALL_AXES: [EulerAxes; 12] = [
+        EulerAxes::XYZ,
+        EulerAxes::XZY,
+        EulerAxes::YXZ,
+        EulerAxes::YZX,
+        EulerAxes::ZXY,
+        EulerAxes::ZYX,
+        EulerAxes::XYX,
+        EulerAxes::XZX,
+        EulerAxes::YXY,
+        EulerAxes::YZY,
+        EulerAxes::ZXZ,
+        EulerAxes::ZYZ,
+    ];
+
+    fn orders() -> impl Iterator<Item = EulerOrder> {
+        ALL_AXES
+            .into_iter()
+            .flat_map(|axes| [EulerOrder::Intrinsic(axes), EulerOrder::Extrinsic(axes)])
+    }
+
+    fn is_proper(order: EulerOrder) -> bool {
+        let (EulerOrder::Intrinsic(axes) | EulerOrder::Extrinsic(axes)) = order;
+        let (i, _, k) = axes.indices();
+        i == k
+    }
+
+    fn assert_same(a: Rotation3<f64>, b: Rotation3<f64>) {
+        for i in 0..3 {
+            assert_abs_diff_eq!(a.apply(basis(i)), b.apply(basis(i)), epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn composition() {
+        let (a, b, c) = (0.3, -0.7, 1.9);
+        let (x, y, z) = (basis(0), basis(1), basis(2));
+        assert_same(
+            Rotation3::from_euler(EulerOrder::Intrinsic(EulerAxes::XYZ), a, b, c),
+            Rotation3::new(x, a)
+                .chain(Rotation3::new(y, b))
+                .chain(Rotation3::new(z, c)),
+        );
+        assert_same(
+            Rotation3::from_euler(EulerOrder::Extrinsic(EulerAxes::XYZ), a, b, c),
+            Rotation3::new(z, c)
+                .chain(Rotation3::new(y, b))
+                .chain(Rotation3::new(x, a)),
+        );
+        assert_same(
+            Rotation3::from_euler(EulerOrder::Intrinsic(EulerAxes::ZXZ), a, b, c),
+            Rotation3::new(z, a)
+                .chain(Rotation3::new(x, b))
+                .chain(Rotation3::new(z, c)),
+        );
+        // Yaw rotates `x`-axis towards `y`-axis.
+        assert_abs_diff_eq!(
+            Rotation3::from_yaw_pitch_roll(FRAC_PI_2, 0.0, 0.0).apply(x),
+            y,
+            epsilon = EPS
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let angles = [
+            (0.3, -0.7, 1.9),
+            (-2.5, 1.2, -0.1),
+            (3.0, 0.01, -3.0),
+            (0.0, 0.0, 0.0),
+        ];
+        for order in orders() {
+            let proper = is_proper(order);
+            for &(a, b, c) in angles.iter() {
+                let b = if proper { b.abs() } else { b };
+                if b == 0.0 {
+                    continue;
+                }
+                let r = Rotation3::from_euler(order, a, b, c);
+                let (x, y, z) = r.to_euler(order);
+                assert_abs_diff_eq!(x, a, epsilon = 1e-9);
+                assert_abs_diff_eq!(y, b, epsilon = 1e-9);
+                assert_abs_diff_eq!(z, c, epsilon = 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn gimbal_lock() {
+        for order in orders() {
+            let proper = is_proper(order);
+            for b in if proper {
+                [0.0, PI]
+            } else {
+                [FRAC_PI_2, -FRAC_PI_2]
+            } {
+                let r = Rotation3::from_euler(order, 0.4, b, -1.1);
+                let (x, y, z) = r.to_euler(order);
+                assert_abs_diff_eq!(y.abs(), b.abs(), epsilon = 1e-7);
+                match order {
+                    EulerOrder::Intrinsic(_) => assert_eq!(z, 0.0),
+                    EulerOrder::Extrinsic(_) => assert_eq!(x, 0.0),
+                }
+                assert_same(Rotation3::from_euler(order, x, y, z), r);
+            }
+        }
+    }
+
+    #[test]
+    fn yaw_pitch_roll() {
+        let r = Rotation3::from_yaw_pitch_roll(1.0, -0.5, 2.0);
+        let (yaw, pitch, roll) = r.to_yaw_pitch_roll();
+        assert_abs_diff_eq!(yaw, 1.0, epsilon = EPS);
+        assert_abs_diff_eq!(pitch, -0.5, epsilon = EPS);
+        assert_abs_diff_eq!(roll, 2.0, epsilon = EPS);
+        assert_same(
+            r,
+            Rotation3::from_euler(EulerOrder::Extrinsic(EulerAxes::XYZ), 2.0, -0.5, 1.0),
+        );
+    }
+}
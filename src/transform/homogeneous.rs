@@ -0,0 +1,339 @@
+use crate::{
+    batch::{Lanes, PointBatch},
+    matrix::DetStrategy,
+    traits::{Dot, Outer},
+    transform::{Affine, Directional, Linear, Reorder, Shift},
+    Matrix, Transform, Vector,
+};
+#[cfg(feature = "approx")]
+use approx::{abs_diff_eq, AbsDiffEq};
+use num_traits::{Num, One, Zero};
+
+/// Compose homogeneous matrix from linear and shift parts.
+///
+/// Panics if `L != N + 1`.
+pub(crate) fn join_homogeneous<T, const N: usize, const L: usize>(
+    lin: Matrix<T, N, N>,
+    shift: Vector<T, N>,
+) -> Matrix<T, L, L>
+where
+    T: Zero + One + Copy,
+{
+    assert_eq!(
+        L,
+        N + 1,
+        "Homogeneous matrix must be one row and one column larger"
+    );
+    Matrix::indices().map(|(i, j)| match (i < N, j < N) {
+        (true, true) => lin[(i, j)],
+        (true, false) => shift[i],
+        (false, true) => T::zero(),
+        (false, false) => T::one(),
+    })
+}
+
+/// Split homogeneous matrix into linear and shift parts.
+///
+/// Returns `None` if the last row of the matrix isn't `[0, ..., 0, 1]`.
+/// Panics if `L != N + 1`.
+pub(crate) fn split_homogeneous<T, const N: usize, const L: usize>(
+    mat: Matrix<T, L, L>,
+) -> Option<(Matrix<T, N, N>, Vector<T, N>)>
+where
+    T: Zero + One + PartialEq + Copy,
+{
+    assert_eq!(
+        L,
+        N + 1,
+        "Homogeneous matrix must be one row and one column larger"
+    );
+    if (0..N).any(|j| mat[(N, j)] != T::zero()) || mat[(N, N)] != T::one() {
+        return None;
+    }
+    Some((
+        Matrix::indices().map(|(i, j)| mat[(i, j)]),
+        Vector::indices().map(|i| mat[(i, N)]),
+    ))
+}
+
+/// Projective transformation of `N`-dimensional space represented by `L`x`L` homogeneous matrix, where `L = N + 1`.
+///
+/// The point is extended with unit component, multiplied by the matrix and then divided by the last component.
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
+pub struct Homogeneous<T, const N: usize, const L: usize> {
+    mat: Matrix<T, L, L>,
+}
+
+pub type Homogeneous2<T> = Homogeneous<T, 2, 3>;
+pub type Homogeneous3<T> = Homogeneous<T, 3, 4>;
+
+impl<T, const N: usize, const L: usize> Homogeneous<T, N, L> {
+    /// Create transformation from homogeneous matrix.
+    ///
+    /// Panics if `L != N + 1`.
+    pub fn from_matrix(mat: Matrix<T, L, L>) -> Self {
+        assert_eq!(
+            L,
+            N + 1,
+            "Homogeneous matrix must be one row and one column larger"
+        );
+        Self { mat }
+    }
+    pub fn into_matrix(self) -> Matrix<T, L, L> {
+        self.mat
+    }
+}
+impl<T, const N: usize, const L: usize> From<Matrix<T, L, L>> for Homogeneous<T, N, L> {
+    fn from(mat: Matrix<T, L, L>) -> Self {
+        Self::from_matrix(mat)
+    }
+}
+impl<T, const N: usize, const L: usize> From<Homogeneous<T, N, L>> for Matrix<T, L, L> {
+    fn from(hom: Homogeneous<T, N, L>) -> Self {
+        hom.into_matrix()
+    }
+}
+
+impl<T, const N: usize, const L: usize> Homogeneous<T, N, L>
+where
+    T: Num + Copy,
+{
+    /// Convert to affine transformation.
+    ///
+    /// Returns `None` if the transformation isn't affine, i.e. the last row of the matrix isn't `[0, ..., 0, 1]`.
+    pub fn to_affine(&self) -> Option<Affine<T, N>> {
+        split_homogeneous(self.mat)
+            .map(|(lin, shift)| Affine::new(Shift::from(shift), Linear::from(lin)))
+    }
+
+    /// Jacobian matrix of the transformation at specified point.
+    pub fn jacobian(&self, pos: Vector<T, N>) -> Matrix<T, N, N> {
+        let lin: Matrix<T, N, N> = Matrix::indices().map(|(i, j)| self.mat[(i, j)]);
+        let shift: Vector<T, N> = Vector::indices().map(|i| self.mat[(i, N)]);
+        let proj: Vector<T, N> = Vector::indices().map(|j| self.mat[(N, j)]);
+        let den = proj.dot(pos) + self.mat[(N, N)];
+        let res = (lin.dot(pos) + shift) / den;
+        (lin - res.outer(proj)) / den
+    }
+}
+
+impl<T, const N: usize, const L: usize> Transform<Vector<T, N>> for Homogeneous<T, N, L>
+where
+    T: DetStrategy,
+{
+    fn identity() -> Self {
+        Self::from_matrix(Matrix::one())
+    }
+    fn inv(self) -> Self {
+        Self {
+            mat: self.mat.inv(),
+        }
+    }
+    fn apply(&self, pos: Vector<T, N>) -> Vector<T, N> {
+        let ext = Vector::<usize, L>::indices().map(|i| if i < N { pos[i] } else { T::one() });
+        let res = self.mat.dot(ext);
+        Vector::indices().map(|i| res[i] / res[N])
+    }
+    fn batched(&self) -> impl Fn(PointBatch<Vector<T, N>>) -> PointBatch<Vector<T, N>> {
+        let mat = self.mat.map(Lanes::splat);
+        move |pos| {
+            let ext =
+                Vector::<usize, L>::indices().map(|i| if i < N { pos[i] } else { Lanes::one() });
+            let res = mat.dot(ext);
+            Vector::indices().map(|i| res[i] / res[N])
+        }
+    }
+    fn deriv(&self, pos: Vector<T, N>, dir: Vector<T, N>) -> Vector<T, N> {
+        let ext = Vector::<usize, L>::indices().map(|i| if i < N { pos[i] } else { T::one() });
+        let dext = Vector::<usize, L>::indices().map(|i| if i < N { dir[i] } else { T::zero() });
+        let (res, dres) = (self.mat.dot(ext), self.mat.dot(dext));
+        let w = res[N];
+        Vector::indices().map(|i| (dres[i] * w - res[i] * dres[N]) / (w * w))
+    }
+    fn chain(self, other: Self) -> Self {
+        Self {
+            mat: self.mat.dot(other.mat),
+        }
+    }
+}
+
+impl<T, const N: usize, const L: usize> Directional<Vector<T, N>> for Homogeneous<T, N, L>
+where
+    Self: Transform<Vector<T, N>>,
+    T: Num + Copy,
+    Linear<T, N>: Directional<Vector<T, N>>,
+{
+    fn apply_dir(&self, pos: Vector<T, N>, dir: Vector<T, N>) -> Vector<T, N> {
+        Linear::from(self.jacobian(pos)).apply_dir(pos, dir)
+    }
+    fn apply_normal(&self, pos: Vector<T, N>, normal: Vector<T, N>) -> Vector<T, N> {
+        Linear::from(self.jacobian(pos)).apply_normal(pos, normal)
+    }
+}
+
+impl<T, const N: usize, const L: usize> From<Affine<T, N>> for Homogeneous<T, N, L>
+where
+    T: Zero + One + PartialEq + Copy,
+{
+    fn from(aff: Affine<T, N>) -> Self {
+        Self::from_matrix(aff.to_homogeneous())
+    }
+}
+impl<T, const N: usize, const L: usize> From<Linear<T, N>> for Homogeneous<T, N, L>
+where
+    T: Zero + One + PartialEq + Copy,
+{
+    fn from(lin: Linear<T, N>) -> Self {
+        Self::from_matrix(lin.to_homogeneous())
+    }
+}
+impl<T, const N: usize, const L: usize> From<Shift<T, N>> for Homogeneous<T, N, L>
+where
+    T: Zero + One + PartialEq + Copy,
+{
+    fn from(shift: Shift<T, N>) -> Self {
+        Self::from_matrix(shift.to_homogeneous())
+    }
+}
+
+macro_rules! derive_reorder {
+    ($X:ident) => {
+        impl<T, const N: usize, const L: usize> Reorder<$X<T, N>, Vector<T, N>>
+            for Homogeneous<T, N, L>
+        where
+            Self: Transform<Vector<T, N>> + From<$X<T, N>>,
+            $X<T, N>: Transform<Vector<T, N>> + Copy,
+        {
+            fn reorder(self, other: $X<T, N>) -> ($X<T, N>, Homogeneous<T, N, L>) {
+                (
+                    other,
+                    Self::from(other.inv()).chain(self).chain(Self::from(other)),
+                )
+            }
+        }
+        impl<T, const N: usize, const L: usize> Reorder<Homogeneous<T, N, L>, Vector<T, N>>
+            for $X<T, N>
+        where
+            Self: Transform<Vector<T, N>> + Copy,
+            Homogeneous<T, N, L>: Transform<Vector<T, N>> + From<$X<T, N>>,
+        {
+            fn reorder(self, other: Homogeneous<T, N, L>) -> (Homogeneous<T, N, L>, $X<T, N>) {
+                (
+                    Homogeneous::from(self)
+                        .chain(other)
+                        .chain(Homogeneous::from(self.inv())),
+                    self,
+                )
+            }
+        }
+    };
+}
+
+derive_reorder!(Linear);
+derive_reorder!(Shift);
+derive_reorder!(Affine);
+
+#[cfg(feature = "approx")]
+impl<T, const N: usize, const L: usize> AbsDiffEq for Homogeneous<T, N, L>
+where
+    T: AbsDiffEq<Epsilon = T> + Copy,
+{
+    type Epsilon = T;
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        abs_diff_eq!(self.mat, other.mat, epsilon = epsilon)
+    }
+}
+
+#[cfg(all(test, feature = "approx"))]
+mod tests {
+    use super::*;
+    use crate::{
+        matrix::*,
+        transform::{Affine2, Affine3, Rotation3, Scale},
+        vector::*,
+    };
+    use approx::assert_abs_diff_eq;
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let hom = Homogeneous2::from(Matrix3x3::from([
+            [2.0, 0.0, 1.0],
+            [0.0, 2.0, 0.0],
+            [0.0, 1.0, 1.0],
+        ]));
+        let s = serde_json::to_string(&hom).unwrap();
+        assert_eq!(s, "[[2.0,0.0,1.0],[0.0,2.0,0.0],[0.0,1.0,1.0]]");
+        assert_eq!(serde_json::from_str::<Homogeneous2<f64>>(&s).unwrap(), hom);
+        assert!(serde_json::from_str::<Homogeneous<f64, 3, 3>>(&s).is_err());
+    }
+
+    #[test]
+    fn conversions() {
+        let lin = Linear::from(Matrix3x3::from([[1, 2, 0], [0, 1, 0], [3, 0, 1]]));
+        let shift = Shift::from(Vector3::from([1, 2, 3]));
+        let lh: Matrix4x4<i32> = lin.to_homogeneous();
+        let sh: Matrix4x4<i32> = shift.to_homogeneous();
+        assert_eq!(Linear::from_homogeneous(lh), Some(lin));
+        assert_eq!(Shift::from_homogeneous(sh), Some(shift));
+        assert_eq!(Linear::<i32, 3>::from_homogeneous(sh), None);
+        assert_eq!(Shift::<i32, 3>::from_homogeneous(lh), None);
+        assert_eq!(sh.dot(lh), Affine::new(shift, lin).to_homogeneous());
+
+        let scale = Scale::from_mag(3);
+        let h: Matrix4x4<i32> = scale.to_homogeneous();
+        assert_eq!(h, Matrix4x4::diagonal(Vector4::from([3, 3, 3, 1])));
+        assert_eq!(Scale::from_homogeneous(h), Some(scale));
+        assert_eq!(Scale::from_homogeneous(lh), None);
+    }
+
+    #[test]
+    fn affine() {
+        let aff = Affine3::new(
+            Vector3::from([1.0, -2.0, 0.5]).into(),
+            Rotation3::new(Vector3::from([0.0, 0.0, 1.0]), 0.5).to_linear(),
+        );
+        let hom = Homogeneous3::from(aff);
+        let x = Vector3::from([0.3, 2.0, -1.0]);
+        let d = Vector3::from([1.0, 0.0, -1.0]);
+        assert_abs_diff_eq!(hom.apply(x), aff.apply(x), epsilon = 1e-12);
+        assert_abs_diff_eq!(hom.deriv(x, d), aff.deriv(x, d), epsilon = 1e-12);
+        assert_abs_diff_eq!(hom.inv().apply(hom.apply(x)), x, epsilon = 1e-12);
+        assert_eq!(hom.to_affine(), Some(aff));
+    }
+
+    #[test]
+    fn perspective_divide() {
+        let hom = Homogeneous2::from(Matrix3x3::from([
+            [2.0, 0.0, 0.0],
+            [0.0, 2.0, 0.0],
+            [0.0, 1.0, 0.0],
+        ]));
+        assert!(hom.to_affine().is_none());
+        assert_abs_diff_eq!(
+            hom.apply(Vector2::from([1.0, 4.0])),
+            Vector2::from([0.5, 2.0])
+        );
+
+        let (x, d, eps) = (Vector2::from([3.0, 2.0]), Vector2::from([-1.0, 0.5]), 1e-6);
+        assert_abs_diff_eq!(
+            hom.deriv(x, d),
+            (hom.apply(x + d * eps) - hom.apply(x - d * eps)) / (2.0 * eps),
+            epsilon = 1e-6
+        );
+        let other = Homogeneous2::from(Affine2::new(
+            Vector2::from([1.0, 1.0]).into(),
+            Linear::identity(),
+        ));
+        assert_abs_diff_eq!(
+            hom.chain(other).apply(x),
+            hom.apply(other.apply(x)),
+            epsilon = 1e-12
+        );
+    }
+}
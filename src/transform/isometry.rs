@@ -0,0 +1,482 @@
+#[cfg(feature = "rand")]
+use crate::distr::{Normal, Uniform};
+use crate::{
+    batch::PointBatch,
+    transform::{Affine, Directional, Reorder, Rotation2, Rotation3, Shift},
+    Matrix, Transform, Vector,
+};
+#[cfg(feature = "approx")]
+use approx::{abs_diff_eq, AbsDiffEq};
+use num_traits::{Float, NumCast};
+#[cfg(feature = "rand")]
+use rand_::{distributions::Distribution, Rng};
+
+/// Rigid body transformation, i.e. rotation followed by shift.
+///
+/// Transformations are applied in the following order: `S(R(x))`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Isometry<T, R, const N: usize> {
+    shift: Shift<T, N>,
+    rotation: R,
+}
+
+pub type Isometry2<T> = Isometry<T, Rotation2<T>, 2>;
+pub type Isometry3<T> = Isometry<T, Rotation3<T>, 3>;
+
+impl<T, R, const N: usize> Isometry<T, R, N> {
+    /// Construct isometry from shift and rotation components.
+    pub fn new(shift: Shift<T, N>, rotation: R) -> Self {
+        Self { shift, rotation }
+    }
+
+    /// Split into shift and rotation components.
+    pub fn split(self) -> (Shift<T, N>, R) {
+        (self.shift, self.rotation)
+    }
+}
+
+impl<T, R, const N: usize> Isometry<T, R, N>
+where
+    T: Copy,
+    R: Copy,
+{
+    /// Shift component of the transformation.
+    pub fn shift(&self) -> Shift<T, N> {
+        self.shift
+    }
+
+    /// Rotation component of the transformation.
+    pub fn rotation(&self) -> R {
+        self.rotation
+    }
+}
+
+impl<T, R, const N: usize> From<(Shift<T, N>, R)> for Isometry<T, R, N> {
+    fn from((shift, rotation): (Shift<T, N>, R)) -> Self {
+        Self::new(shift, rotation)
+    }
+}
+impl<T, R, const N: usize> From<Isometry<T, R, N>> for (Shift<T, N>, R) {
+    fn from(iso: Isometry<T, R, N>) -> Self {
+        iso.split()
+    }
+}
+
+impl<T, R, const N: usize> Transform<Vector<T, N>> for Isometry<T, R, N>
+where
+    R: Transform<Vector<T, N>> + Copy,
+    Shift<T, N>: Transform<Vector<T, N>>,
+    T: Copy,
+{
+    fn identity() -> Self {
+        Self::new(Shift::identity(), R::identity())
+    }
+    fn inv(self) -> Self {
+        let rotation = self.rotation.inv();
+        Self::new(
+            rotation.apply(self.shift.inv().into_vector()).into(),
+            rotation,
+        )
+    }
+    fn apply(&self, pos: Vector<T, N>) -> Vector<T, N> {
+        self.shift.apply(self.rotation.apply(pos))
+    }
+    fn batched(&self) -> impl Fn(PointBatch<Vector<T, N>>) -> PointBatch<Vector<T, N>> {
+        let (rotation, shift) = (self.rotation.batched(), self.shift.batched());
+        move |pos| shift(rotation(pos))
+    }
+    fn deriv(&self, pos: Vector<T, N>, dir: Vector<T, N>) -> Vector<T, N> {
+        self.rotation.deriv(pos, dir)
+    }
+    fn chain(self, other: Self) -> Self {
+        Self::new(
+            self.shift
+                .chain(self.rotation.apply(other.shift.into_vector()).into()),
+            self.rotation.chain(other.rotation),
+        )
+    }
+}
+
+impl<T, R, const N: usize> Directional<Vector<T, N>> for Isometry<T, R, N>
+where
+    R: Directional<Vector<T, N>> + Copy,
+    Self: Transform<Vector<T, N>>,
+{
+    fn apply_dir(&self, pos: Vector<T, N>, dir: Vector<T, N>) -> Vector<T, N> {
+        self.rotation.apply_dir(pos, dir)
+    }
+    fn apply_normal(&self, pos: Vector<T, N>, normal: Vector<T, N>) -> Vector<T, N> {
+        self.rotation.apply_normal(pos, normal)
+    }
+}
+
+impl<T, R, const N: usize> Reorder<Shift<T, N>, Vector<T, N>> for Isometry<T, R, N>
+where
+    Self: Transform<Vector<T, N>>,
+    R: Transform<Vector<T, N>>,
+    Shift<T, N>: Transform<Vector<T, N>>,
+{
+    fn reorder(self, other: Shift<T, N>) -> (Shift<T, N>, Self) {
+        (self.rotation.apply(other.into_vector()).into(), self)
+    }
+}
+impl<T, R, const N: usize> Reorder<Isometry<T, R, N>, Vector<T, N>> for Shift<T, N>
+where
+    Self: Transform<Vector<T, N>>,
+    Isometry<T, R, N>: Transform<Vector<T, N>>,
+    R: Transform<Vector<T, N>> + Copy,
+    T: Copy,
+{
+    fn reorder(self, other: Isometry<T, R, N>) -> (Isometry<T, R, N>, Self) {
+        let shift = other.rotation.inv().apply(self.into_vector()).into();
+        (other, shift)
+    }
+}
+
+macro_rules! derive_rotation_reorder {
+    ($R:ident, $N:expr) => {
+        impl<T> Reorder<$R<T>, Vector<T, $N>> for Isometry<T, $R<T>, $N>
+        where
+            Self: Transform<Vector<T, $N>>,
+            $R<T>: Transform<Vector<T, $N>> + Copy,
+        {
+            fn reorder(self, other: $R<T>) -> ($R<T>, Self) {
+                let inv = other.inv();
+                let rotation = inv.chain(self.rotation).chain(other);
+                (
+                    other,
+                    Self::new(inv.apply(self.shift.into_vector()).into(), rotation),
+                )
+            }
+        }
+        impl<T> Reorder<Isometry<T, $R<T>, $N>, Vector<T, $N>> for $R<T>
+        where
+            Self: Transform<Vector<T, $N>> + Copy,
+            Isometry<T, $R<T>, $N>: Transform<Vector<T, $N>>,
+        {
+            fn reorder(self, other: Isometry<T, $R<T>, $N>) -> (Isometry<T, $R<T>, $N>, Self) {
+                let rotation = self.chain(other.rotation).chain(self.inv());
+                (
+                    Isometry::new(self.apply(other.shift.into_vector()).into(), rotation),
+                    self,
+                )
+            }
+        }
+    };
+}
+
+derive_rotation_reorder!(Rotation2, 2);
+derive_rotation_reorder!(Rotation3, 3);
+
+/// `sin(x) / x` that is continuous at zero.
+fn sinc<T: Float>(x: T) -> T {
+    if x * x < T::epsilon().sqrt() {
+        T::one() - x * x / T::from(6).unwrap()
+    } else {
+        x.sin() / x
+    }
+}
+
+impl<T> Isometry2<T>
+where
+    T: Float + NumCast,
+{
+    /// Affine transformation equal to the isometry.
+    pub fn to_affine(self) -> Affine<T, 2> {
+        Affine::new(self.shift, self.rotation.to_linear())
+    }
+
+    /// Homogeneous 3x3 matrix of the transformation.
+    pub fn to_homogeneous(&self) -> Matrix<T, 3, 3> {
+        self.to_affine().to_homogeneous()
+    }
+
+    /// Raise the transformation to the power `t` along the screw motion.
+    fn powf(self, t: T) -> Self {
+        let angle = self.rotation.angle();
+        let two = T::from(2).unwrap();
+        // Translation is `V(θ)·v`, where `V(θ) = exp(iθ/2)·sinc(θ/2)` in complex form.
+        let scale = t * sinc(t * angle / two) / sinc(angle / two);
+        let shift = Rotation2::new((t - T::one()) * angle / two).apply(self.shift.into_vector());
+        Self::new(Shift::from(shift * scale), Rotation2::new(t * angle))
+    }
+
+    /// Screw linear interpolation between transformations.
+    ///
+    /// The rotation is interpolated with constant angular velocity along the shortest arc,
+    /// and the points are moving along circular arcs around the fixed center of relative rotation.
+    pub fn sclerp(self, other: Self, t: T) -> Self {
+        self.chain(self.inv().chain(other).powf(t))
+    }
+}
+
+impl<T> Isometry3<T>
+where
+    T: Float + NumCast,
+{
+    /// Affine transformation equal to the isometry.
+    pub fn to_affine(self) -> Affine<T, 3> {
+        Affine::new(self.shift, self.rotation.to_linear())
+    }
+
+    /// Homogeneous 4x4 matrix of the transformation.
+    pub fn to_homogeneous(&self) -> Matrix<T, 4, 4> {
+        self.to_affine().to_homogeneous()
+    }
+
+    /// Raise the transformation to the power `t` along the screw motion.
+    ///
+    /// Performed as the exponential of the scaled logarithm in `se(3)`.
+    pub(crate) fn powf(self, t: T) -> Self {
+        let small = T::epsilon().sqrt();
+        let (two, six) = (T::from(2).unwrap(), T::from(6).unwrap());
+
+        let omega = self.rotation.to_rotation_vector();
+        let angle_sqr = omega.square_length();
+        let shift = self.shift.into_vector();
+        // `v = V⁻¹(ω)·shift`
+        let c = if angle_sqr < small {
+            T::one() / T::from(12).unwrap() + angle_sqr / T::from(720).unwrap()
+        } else {
+            let half = angle_sqr.sqrt() / two;
+            (T::one() - half / half.tan()) / angle_sqr
+        };
+        let v = shift - omega.cross(shift) / two + omega.cross(omega.cross(shift)) * c;
+
+        let (omega, v) = (omega * t, v * t);
+        let angle_sqr = omega.square_length();
+        // `shift = V(ω)·v`
+        let (a, b) = if angle_sqr < small {
+            (
+                T::one() / two - angle_sqr / T::from(24).unwrap(),
+                T::one() / six - angle_sqr / T::from(120).unwrap(),
+            )
+        } else {
+            let angle = angle_sqr.sqrt();
+            let sin_half = (angle / two).sin();
+            (
+                two * sin_half * sin_half / angle_sqr,
+                (angle - angle.sin()) / (angle_sqr * angle),
+            )
+        };
+        let shift = v + omega.cross(v) * a + omega.cross(omega.cross(v)) * b;
+        Self::new(Shift::from(shift), Rotation3::from_rotation_vector(omega))
+    }
+
+    /// Screw linear interpolation between transformations.
+    ///
+    /// The motion is a constant-speed rotation around the screw axis combined with translation along it,
+    /// the same as ScLERP of unit dual quaternions.
+    pub fn sclerp(self, other: Self, t: T) -> Self {
+        self.chain(self.inv().chain(other).powf(t))
+    }
+}
+
+impl<T> From<Isometry2<T>> for Affine<T, 2>
+where
+    T: Float + NumCast,
+{
+    fn from(iso: Isometry2<T>) -> Self {
+        iso.to_affine()
+    }
+}
+impl<T> From<Isometry3<T>> for Affine<T, 3>
+where
+    T: Float + NumCast,
+{
+    fn from(iso: Isometry3<T>) -> Self {
+        iso.to_affine()
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<T, R, const N: usize> Distribution<Isometry<T, R, N>> for Normal
+where
+    Uniform: Distribution<R>,
+    Normal: Distribution<Shift<T, N>>,
+{
+    /// Isometry with normally distributed shift and uniformly distributed rotation.
+    fn sample<G: Rng + ?Sized>(&self, rng: &mut G) -> Isometry<T, R, N> {
+        Isometry::new(self.sample(rng), rng.sample(&Uniform))
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T, R, const N: usize> AbsDiffEq for Isometry<T, R, N>
+where
+    R: AbsDiffEq<Epsilon = T>,
+    T: AbsDiffEq<Epsilon = T> + Copy,
+{
+    type Epsilon = T;
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        abs_diff_eq!(self.shift, other.shift, epsilon = epsilon)
+            && abs_diff_eq!(self.rotation, other.rotation, epsilon = epsilon)
+    }
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::vector::*;
+    use approx::assert_abs_diff_eq;
+    use rand_::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+    const EPS: f64 = 1e-12;
+
+    macro_rules! base_tests {
+        ($X:ident, $R:ident, $V:ident, $seed:expr) => {
+            use super::*;
+
+            #[test]
+            fn mapping() {
+                let mut rng = XorShiftRng::seed_from_u64($seed);
+                for _ in 0..SAMPLE_ATTEMPTS {
+                    let a: $X<f64> = rng.sample(&Normal);
+                    let (x, y): ($V<f64>, $V<f64>) = (rng.sample(&Normal), rng.sample(&Normal));
+                    assert_abs_diff_eq!(
+                        (a.apply(x) - a.apply(y)).length(),
+                        (x - y).length(),
+                        epsilon = EPS
+                    );
+                    assert_abs_diff_eq!(
+                        a.apply(x),
+                        a.shift().apply(a.rotation().apply(x)),
+                        epsilon = EPS
+                    );
+                    assert_abs_diff_eq!(a.deriv(x, y), a.rotation().apply(y), epsilon = EPS);
+                    assert_abs_diff_eq!(a.apply_normal(x, y), a.rotation().apply(y), epsilon = EPS);
+                }
+            }
+
+            #[test]
+            fn chaining() {
+                let mut rng = XorShiftRng::seed_from_u64($seed + 1);
+                for _ in 0..SAMPLE_ATTEMPTS {
+                    let a: $X<f64> = rng.sample(&Normal);
+                    let b: $X<f64> = rng.sample(&Normal);
+                    let x: $V<f64> = rng.sample(&Normal);
+                    assert_abs_diff_eq!(a.chain(b).apply(x), a.apply(b.apply(x)), epsilon = EPS);
+                }
+            }
+
+            #[test]
+            fn inversion() {
+                let mut rng = XorShiftRng::seed_from_u64($seed + 2);
+                for _ in 0..SAMPLE_ATTEMPTS {
+                    let a: $X<f64> = rng.sample(&Normal);
+                    let x: $V<f64> = rng.sample(&Normal);
+                    assert_abs_diff_eq!(a.inv().apply(a.apply(x)), x, epsilon = EPS);
+                    assert_abs_diff_eq!(a.chain(a.inv()), $X::identity(), epsilon = EPS);
+                }
+            }
+
+            #[test]
+            fn reordering() {
+                let mut rng = XorShiftRng::seed_from_u64($seed + 3);
+                for _ in 0..SAMPLE_ATTEMPTS {
+                    let a: $X<f64> = rng.sample(&Normal);
+                    let s = Shift::from(rng.sample::<$V<f64>, _>(&Normal));
+                    let r: $R<f64> = rng.sample(&Uniform);
+                    let x: $V<f64> = rng.sample(&Normal);
+
+                    let (s_, a_) = a.reorder(s);
+                    assert_abs_diff_eq!(a.apply(s.apply(x)), s_.apply(a_.apply(x)), epsilon = EPS);
+                    let (a_, s_) = s.reorder(a);
+                    assert_abs_diff_eq!(s.apply(a.apply(x)), a_.apply(s_.apply(x)), epsilon = EPS);
+                    let (r_, a_) = a.reorder(r);
+                    assert_abs_diff_eq!(a.apply(r.apply(x)), r_.apply(a_.apply(x)), epsilon = EPS);
+                    let (a_, r_) = r.reorder(a);
+                    assert_abs_diff_eq!(r.apply(a.apply(x)), a_.apply(r_.apply(x)), epsilon = EPS);
+                }
+            }
+
+            #[test]
+            fn conversion() {
+                let mut rng = XorShiftRng::seed_from_u64($seed + 4);
+                for _ in 0..SAMPLE_ATTEMPTS {
+                    let a: $X<f64> = rng.sample(&Normal);
+                    let x: $V<f64> = rng.sample(&Normal);
+                    assert_abs_diff_eq!(a.to_affine().apply(x), a.apply(x), epsilon = EPS);
+                    let h = a.to_homogeneous();
+                    assert_eq!(Affine::from_homogeneous(h), Some(a.to_affine()));
+                }
+            }
+
+            #[test]
+            fn sclerp() {
+                let mut rng = XorShiftRng::seed_from_u64($seed + 5);
+                for _ in 0..SAMPLE_ATTEMPTS {
+                    let a: $X<f64> = rng.sample(&Normal);
+                    let b: $X<f64> = rng.sample(&Normal);
+                    let x: $V<f64> = rng.sample(&Normal);
+                    assert_abs_diff_eq!(a.sclerp(b, 0.0).apply(x), a.apply(x), epsilon = 1e-9);
+                    assert_abs_diff_eq!(a.sclerp(b, 1.0).apply(x), b.apply(x), epsilon = 1e-9);
+                    // Constant velocity of the screw motion.
+                    let (c, d) = (a.sclerp(b, 0.25), a.sclerp(b, 0.5));
+                    assert_abs_diff_eq!(
+                        a.inv().chain(c).chain(a.inv().chain(c)).apply(x),
+                        a.inv().chain(d).apply(x),
+                        epsilon = 1e-9
+                    );
+                }
+                // Pure translation is interpolated linearly.
+                let a = $X::new($V::fill(1.0).into(), $R::identity());
+                let b = $X::new($V::fill(3.0).into(), $R::identity());
+                assert_abs_diff_eq!(
+                    a.sclerp(b, 0.5),
+                    $X::new($V::fill(2.0).into(), $R::identity())
+                );
+            }
+        };
+    }
+
+    mod i2d {
+        base_tests!(Isometry2, Rotation2, Vector2, 0x150);
+
+        #[test]
+        fn screw() {
+            use core::f64::consts::PI;
+            // Rotation by `π/2` around the point `(1, 0)`.
+            let c = Vector2::from([1.0, 0.0]);
+            let r = Rotation2::new(PI / 2.0);
+            let a = Isometry2::identity();
+            let b = Isometry2::new((c - r.apply(c)).into(), r);
+            let m = a.sclerp(b, 0.5);
+            assert_abs_diff_eq!(m.apply(c), c, epsilon = EPS);
+            assert_abs_diff_eq!(m.rotation(), Rotation2::new(PI / 4.0), epsilon = EPS);
+        }
+    }
+
+    mod i3d {
+        base_tests!(Isometry3, Rotation3, Vector3, 0x160);
+
+        #[test]
+        fn screw() {
+            use core::f64::consts::PI;
+            // Rotation by `3π/4` around the axis parallel to `z` passing through `(1, 2, 0)` with pitch along the axis.
+            let (c, z) = (
+                Vector3::from([1.0, 2.0, 0.0]),
+                Vector3::from([0.0, 0.0, 1.0]),
+            );
+            let screw = |t: f64| {
+                let r = Rotation3::new(z, 0.75 * PI * t);
+                Isometry3::new((c - r.apply(c) + z * 4.0 * t).into(), r)
+            };
+            let (a, b) = (screw(0.0), screw(1.0));
+            let x = Vector3::from([-0.5, 0.3, 2.0]);
+            for t in [0.1, 0.3, 0.5, 0.9] {
+                assert_abs_diff_eq!(a.sclerp(b, t).apply(x), screw(t).apply(x), epsilon = EPS);
+            }
+            // Tiny rotation angle.
+            let (a, b) = (screw(0.0), screw(1e-9));
+            let m = a.sclerp(b, 0.5);
+            assert_abs_diff_eq!(m.apply(x), screw(0.5e-9).apply(x), epsilon = EPS);
+        }
+    }
+}
@@ -1,20 +1,30 @@
 #[cfg(feature = "rand")]
 use crate::distr::{Invertible, Normal};
 use crate::{
-    traits::{Dot, Normalize},
-    transform::{Reorder, Shift, Directional},
+    batch::{Lanes, PointBatch},
+    matrix::DetStrategy,
+    traits::{Dot, Epsilon, NormL1, Normalize},
+    transform::{
+        homogeneous::{join_homogeneous, split_homogeneous},
+        Directional, Reorder, Shift,
+    },
     Matrix, Transform, Vector,
 };
 #[cfg(feature = "approx")]
 use approx::{abs_diff_eq, AbsDiffEq};
 use core::ops::Neg;
-use num_traits::{Float, Num, NumCast, One, Inv};
+use num_traits::{Float, Inv, Num, NumCast, One, Zero};
 #[cfg(feature = "rand")]
 use rand_::{distributions::Distribution, Rng};
 
 /// Linear transformation.
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Linear<T, const N: usize> {
     lin: Matrix<T, N, N>,
 }
@@ -44,7 +54,7 @@ impl<T, const N: usize> From<Linear<T, N>> for Matrix<T, N, N> {
 
 impl<T, const N: usize> Transform<Vector<T, N>> for Linear<T, N>
 where
-    T: Neg<Output = T> + Num + Copy,
+    T: DetStrategy,
 {
     fn identity() -> Self {
         Self { lin: Matrix::one() }
@@ -57,6 +67,10 @@ where
     fn apply(&self, pos: Vector<T, N>) -> Vector<T, N> {
         self.lin.dot(pos)
     }
+    fn batched(&self) -> impl Fn(PointBatch<Vector<T, N>>) -> PointBatch<Vector<T, N>> {
+        let lin = self.lin.map(Lanes::splat);
+        move |pos| lin.dot(pos)
+    }
     fn deriv(&self, _pos: Vector<T, N>, dir: Vector<T, N>) -> Vector<T, N> {
         self.apply(dir)
     }
@@ -67,13 +81,49 @@ where
     }
 }
 
+impl<T, const N: usize> Linear<T, N>
+where
+    T: DetStrategy + NormL1,
+    <T as NormL1>::Output: Epsilon + Num + Copy,
+{
+    /// Inverse transformation, or `None` if the transformation is degenerate.
+    pub fn try_inv(self) -> Option<Self> {
+        self.lin.try_inv().map(Self::from_matrix)
+    }
+}
+
+impl<T, const N: usize> Linear<T, N>
+where
+    T: Zero + One + PartialEq + Copy,
+{
+    /// Homogeneous `L`x`L` matrix of the transformation, where `L = N + 1`.
+    pub fn to_homogeneous<const L: usize>(&self) -> Matrix<T, L, L> {
+        join_homogeneous(self.lin, Vector::zero())
+    }
+
+    /// Create transformation from homogeneous `L`x`L` matrix, where `L = N + 1`.
+    ///
+    /// Returns `None` if the matrix has non-zero shift component or its last row isn't `[0, ..., 0, 1]`.
+    pub fn from_homogeneous<const L: usize>(mat: Matrix<T, L, L>) -> Option<Self> {
+        split_homogeneous(mat).and_then(|(lin, shift)| {
+            if shift.into_iter().all(|x| x == T::zero()) {
+                Some(Self::from_matrix(lin))
+            } else {
+                None
+            }
+        })
+    }
+}
+
 impl<T, const N: usize> Linear<T, N>
 where
     T: Neg<Output = T> + Num + Copy,
-    Matrix<T, N, N>: Inv<Output=Matrix<T, N, N>>,
+    Matrix<T, N, N>: Inv<Output = Matrix<T, N, N>>,
 {
     pub fn normal_transform(self) -> Self {
-        Self { lin: self.lin.inv().transpose() }
+        Self {
+            lin: self.lin.inv().transpose(),
+        }
     }
 }
 
@@ -82,7 +132,7 @@ where
     Self: Transform<Vector<T, N>>,
     T: Neg<Output = T> + Num + Copy,
     Vector<T, N>: Normalize,
-    Matrix<T, N, N>: Inv<Output=Matrix<T, N, N>>,
+    Matrix<T, N, N>: Inv<Output = Matrix<T, N, N>>,
 {
     fn apply_dir(&self, pos: Vector<T, N>, dir: Vector<T, N>) -> Vector<T, N> {
         self.deriv(pos, dir).normalize()
@@ -180,6 +230,15 @@ mod tests {
 
     const SAMPLE_ATTEMPTS: usize = 256;
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let linear = Linear::from(Matrix::from([[1.0, 2.0], [3.0, 4.0]]));
+        let s = serde_json::to_string(&linear).unwrap();
+        assert_eq!(s, "[[1.0,2.0],[3.0,4.0]]");
+        assert_eq!(serde_json::from_str::<Linear<f64, 2>>(&s).unwrap(), linear);
+    }
+
     #[test]
     fn linearity() {
         const EPS: f64 = 1e-14;
@@ -235,6 +294,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn fallible_inversion() {
+        const EPS: f64 = 1e-12;
+        let mut rng = XorShiftRng::seed_from_u64(0xBED);
+
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let a: Linear<f64, 3> = rng.sample(&Invertible);
+            assert_abs_diff_eq!(a.try_inv().unwrap(), a.inv(), epsilon = EPS);
+
+            let x: Vector<f64, 3> = rng.sample(&Normal);
+            let y: Vector<f64, 3> = rng.sample(&Normal);
+            let m = Matrix::from([x, y, x * 2.0 - y]);
+            assert!(Linear::from(m).try_inv().is_none());
+        }
+    }
+
     #[test]
     fn look_to_the_direction() {
         const EPS: f64 = 1e-14;
@@ -1,18 +1,34 @@
 mod affine;
 mod chain;
+mod euler;
+mod homogeneous;
+mod isometry;
 mod linear;
+mod moebius;
+mod projective;
 mod rotation;
-mod shift;
 mod scale;
-mod moebius;
+#[cfg(feature = "serde")]
+mod serde;
+mod shift;
+#[cfg(feature = "simd")]
+mod simd;
+mod similarity;
 
 pub use affine::*;
 pub use chain::*;
+pub use euler::*;
+pub use homogeneous::*;
+pub use isometry::*;
 pub use linear::*;
+pub use moebius::*;
+pub use projective::*;
 pub use rotation::*;
-pub use shift::*;
 pub use scale::*;
-pub use moebius::*;
+pub use shift::*;
+pub use similarity::*;
+
+use crate::batch::{apply_batched, Batch, PointBatch};
 
 /// General tansformation trait.
 ///
@@ -28,6 +44,27 @@ pub trait Transform<T> {
     /// Find transformation directional derivative at specified point.
     fn deriv(&self, pos: T, dir: T) -> T;
 
+    /// Function that applies the transformation to a [batch](crate::batch) of points.
+    ///
+    /// By default each point of the batch is transformed separately,
+    /// some transformations override it to process all lanes at once.
+    fn batched(&self) -> impl Fn(PointBatch<T>) -> PointBatch<T>
+    where
+        T: Batch,
+    {
+        move |lanes| T::pack(T::unpack(lanes).map(|pos| self.apply(pos)))
+    }
+    /// Apply the transformation to each point of the slice in place.
+    ///
+    /// Points are processed in batches using [`Self::batched`], so composite transformations
+    /// pass over the slice only once.
+    fn apply_batch(&self, points: &mut [T])
+    where
+        T: Batch,
+    {
+        apply_batched(points, self.batched());
+    }
+
     /// Chain two transformations into a new one.
     ///
     /// `C = A.chain(B)` means that `C(x) = A(B(x))`.
@@ -39,7 +76,7 @@ pub trait Directional<T>: Transform<T> {
     fn apply_dir(&self, pos: T, dir: T) -> T;
 
     /// Returns the result of the normal transformation at the specified position.
-    /// 
+    ///
     /// If the transformation is conformal then the result is equal to `apply_dir`.
     fn apply_normal(&self, pos: T, normal: T) -> T;
 }
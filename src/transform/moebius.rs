@@ -1,12 +1,16 @@
 pub use crate::complex::Moebius;
-use crate::{complex::{Complex, Quaternion}, Transform, transform::Directional, traits::Normalize};
+use crate::{
+    complex::{Complex, Quaternion},
+    traits::Normalize,
+    transform::Directional,
+    Transform,
+};
 use core::ops::Neg;
 use num_traits::{Num, NumCast};
 
-
 impl<T> Transform<Complex<T>> for Moebius<Complex<T>>
 where
-    T: Neg<Output = T> + Num + Copy
+    T: Neg<Output = T> + Num + Copy,
 {
     fn identity() -> Self {
         Moebius::identity()
@@ -29,7 +33,7 @@ where
 
 impl<T> Transform<Quaternion<T>> for Moebius<Complex<T>>
 where
-    T: Neg<Output = T> + Num + NumCast + Copy
+    T: Neg<Output = T> + Num + NumCast + Copy,
 {
     fn identity() -> Self {
         Moebius::identity()
@@ -0,0 +1,286 @@
+use crate::{transform::Homogeneous, Matrix};
+use num_traits::Float;
+
+/// Projective transformation.
+///
+/// It is the same as [`Homogeneous`] transformation, so the point `x` is mapped to `(A·x + b) / (c·x + w)`
+/// where `[[A, b], [cᵀ, w]]` is the homogeneous matrix.
+pub type Projective<T, const N: usize, const L: usize> = Homogeneous<T, N, L>;
+
+pub type Projective2<T> = Projective<T, 2, 3>;
+pub type Projective3<T> = Projective<T, 3, 4>;
+
+impl<T> Projective3<T>
+where
+    T: Float,
+{
+    fn from_rows(rows: [[T; 4]; 4]) -> Self {
+        Self::from_matrix(Matrix::from(rows))
+    }
+
+    /// Perspective projection with OpenGL depth range `[-1, 1]`.
+    ///
+    /// Camera looks along `-z`-axis, `fovy` is a vertical field of view in radians
+    /// and `aspect` is a ratio of width to height.
+    /// Points at `near` and `far` distances are mapped to depth `-1` and `1` respectively.
+    pub fn perspective(fovy: T, aspect: T, near: T, far: T) -> Self {
+        let (o, l) = (T::zero(), T::one());
+        let f = l / (fovy / (l + l)).tan();
+        let d = near - far;
+        Self::from_rows([
+            [f / aspect, o, o, o],
+            [o, f, o, o],
+            [o, o, (far + near) / d, (far * near + far * near) / d],
+            [o, o, -l, o],
+        ])
+    }
+
+    /// Perspective projection with Vulkan/Direct3D depth range `[0, 1]`.
+    ///
+    /// Points at `near` and `far` distances are mapped to depth `0` and `1` respectively.
+    pub fn perspective_zo(fovy: T, aspect: T, near: T, far: T) -> Self {
+        let (o, l) = (T::zero(), T::one());
+        let f = l / (fovy / (l + l)).tan();
+        let d = near - far;
+        Self::from_rows([
+            [f / aspect, o, o, o],
+            [o, f, o, o],
+            [o, o, far / d, far * near / d],
+            [o, o, -l, o],
+        ])
+    }
+
+    /// Perspective projection with infinite far plane and OpenGL depth range `[-1, 1]`.
+    pub fn perspective_infinite(fovy: T, aspect: T, near: T) -> Self {
+        let (o, l) = (T::zero(), T::one());
+        let f = l / (fovy / (l + l)).tan();
+        Self::from_rows([
+            [f / aspect, o, o, o],
+            [o, f, o, o],
+            [o, o, -l, -(near + near)],
+            [o, o, -l, o],
+        ])
+    }
+
+    /// Perspective projection with infinite far plane and Vulkan/Direct3D depth range `[0, 1]`.
+    pub fn perspective_infinite_zo(fovy: T, aspect: T, near: T) -> Self {
+        let (o, l) = (T::zero(), T::one());
+        let f = l / (fovy / (l + l)).tan();
+        Self::from_rows([
+            [f / aspect, o, o, o],
+            [o, f, o, o],
+            [o, o, -l, -near],
+            [o, o, -l, o],
+        ])
+    }
+
+    /// Perspective projection with reversed depth, i.e. points at `near` and `far` distances
+    /// are mapped to depth `1` and `0` respectively.
+    ///
+    /// Reversed depth is only provided for `[0, 1]` range because only there
+    /// it improves the precision of floating-point depth buffer.
+    pub fn perspective_reversed_zo(fovy: T, aspect: T, near: T, far: T) -> Self {
+        let (o, l) = (T::zero(), T::one());
+        let f = l / (fovy / (l + l)).tan();
+        let d = far - near;
+        Self::from_rows([
+            [f / aspect, o, o, o],
+            [o, f, o, o],
+            [o, o, near / d, far * near / d],
+            [o, o, -l, o],
+        ])
+    }
+
+    /// Perspective projection with infinite far plane and reversed depth in range `[0, 1]`.
+    pub fn perspective_infinite_reversed_zo(fovy: T, aspect: T, near: T) -> Self {
+        let (o, l) = (T::zero(), T::one());
+        let f = l / (fovy / (l + l)).tan();
+        Self::from_rows([
+            [f / aspect, o, o, o],
+            [o, f, o, o],
+            [o, o, o, near],
+            [o, o, -l, o],
+        ])
+    }
+
+    /// Orthographic projection with OpenGL depth range `[-1, 1]`.
+    ///
+    /// Camera looks along `-z`-axis, the view box is mapped to `[-1, 1]` cube.
+    pub fn orthographic(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        let (o, l) = (T::zero(), T::one());
+        let (w, h, d) = (right - left, top - bottom, far - near);
+        Self::from_rows([
+            [(l + l) / w, o, o, -(right + left) / w],
+            [o, (l + l) / h, o, -(top + bottom) / h],
+            [o, o, -(l + l) / d, -(far + near) / d],
+            [o, o, o, l],
+        ])
+    }
+
+    /// Orthographic projection with Vulkan/Direct3D depth range `[0, 1]`.
+    pub fn orthographic_zo(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        let (o, l) = (T::zero(), T::one());
+        let (w, h, d) = (right - left, top - bottom, far - near);
+        Self::from_rows([
+            [(l + l) / w, o, o, -(right + left) / w],
+            [o, (l + l) / h, o, -(top + bottom) / h],
+            [o, o, -l / d, -near / d],
+            [o, o, o, l],
+        ])
+    }
+}
+
+#[cfg(all(test, feature = "approx"))]
+mod tests {
+    use super::*;
+    use crate::{
+        matrix::*,
+        prelude::*,
+        transform::{Affine3, Chain, Directional, Linear},
+        vector::*,
+    };
+    use approx::assert_abs_diff_eq;
+    use core::f64::consts::FRAC_PI_2;
+
+    const EPS: f64 = 1e-12;
+
+    fn depth(p: &Projective3<f64>, dist: f64) -> f64 {
+        p.apply(Vector3::from([0.0, 0.0, -dist])).z()
+    }
+
+    #[test]
+    fn perspective_depth() {
+        let (fovy, aspect, near, far) = (FRAC_PI_2, 1.5, 0.1, 100.0);
+        let p = Projective3::perspective(fovy, aspect, near, far);
+        assert_abs_diff_eq!(depth(&p, near), -1.0, epsilon = EPS);
+        assert_abs_diff_eq!(depth(&p, far), 1.0, epsilon = EPS);
+        let p = Projective3::perspective_zo(fovy, aspect, near, far);
+        assert_abs_diff_eq!(depth(&p, near), 0.0, epsilon = EPS);
+        assert_abs_diff_eq!(depth(&p, far), 1.0, epsilon = EPS);
+        let p = Projective3::perspective_reversed_zo(fovy, aspect, near, far);
+        assert_abs_diff_eq!(depth(&p, near), 1.0, epsilon = EPS);
+        assert_abs_diff_eq!(depth(&p, far), 0.0, epsilon = EPS);
+
+        let p = Projective3::perspective_infinite(fovy, aspect, near);
+        assert_abs_diff_eq!(depth(&p, near), -1.0, epsilon = EPS);
+        assert_abs_diff_eq!(depth(&p, 1e12), 1.0, epsilon = 1e-9);
+        let p = Projective3::perspective_infinite_zo(fovy, aspect, near);
+        assert_abs_diff_eq!(depth(&p, near), 0.0, epsilon = EPS);
+        assert_abs_diff_eq!(depth(&p, 1e12), 1.0, epsilon = 1e-9);
+        let p = Projective3::perspective_infinite_reversed_zo(fovy, aspect, near);
+        assert_abs_diff_eq!(depth(&p, near), 1.0, epsilon = EPS);
+        assert_abs_diff_eq!(depth(&p, 1e12), 0.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn perspective_frustum() {
+        let (aspect, near, far) = (2.0, 1.0, 10.0);
+        let p = Projective3::perspective(FRAC_PI_2, aspect, near, far);
+        // Top right corner of the far plane.
+        let x = Vector3::from([far * aspect, far, -far]);
+        assert_abs_diff_eq!(p.apply(x), Vector3::fill(1.0), epsilon = EPS);
+        let x = Vector3::from([-near * aspect, -near, -near]);
+        assert_abs_diff_eq!(p.apply(x), Vector3::fill(-1.0), epsilon = EPS);
+    }
+
+    #[test]
+    fn orthographic() {
+        let p = Projective3::orthographic(-1.0, 3.0, 0.0, 2.0, 1.0, 5.0);
+        let (a, b) = (
+            Vector3::from([-1.0, 0.0, -1.0]),
+            Vector3::from([3.0, 2.0, -5.0]),
+        );
+        assert_abs_diff_eq!(p.apply(a), Vector3::fill(-1.0), epsilon = EPS);
+        assert_abs_diff_eq!(p.apply(b), Vector3::fill(1.0), epsilon = EPS);
+        assert!(p.to_affine().is_some());
+        let p = Projective3::orthographic_zo(-1.0, 3.0, 0.0, 2.0, 1.0, 5.0);
+        assert_abs_diff_eq!(p.apply(a), Vector3::from([-1.0, -1.0, 0.0]), epsilon = EPS);
+        assert_abs_diff_eq!(p.apply(b), Vector3::fill(1.0), epsilon = EPS);
+    }
+
+    #[test]
+    fn inversion() {
+        let x = Vector3::from([0.3, -0.2, -4.0]);
+        for p in [
+            Projective3::perspective(1.0, 1.5, 0.1, 100.0),
+            Projective3::perspective_zo(1.0, 1.5, 0.1, 100.0),
+            Projective3::perspective_infinite(1.0, 1.5, 0.1),
+            Projective3::perspective_infinite_zo(1.0, 1.5, 0.1),
+            Projective3::perspective_reversed_zo(1.0, 1.5, 0.1, 100.0),
+            Projective3::perspective_infinite_reversed_zo(1.0, 1.5, 0.1),
+            Projective3::orthographic(-2.0, 2.0, -1.0, 1.0, 0.1, 10.0),
+            Projective3::orthographic_zo(-2.0, 2.0, -1.0, 1.0, 0.1, 10.0),
+        ] {
+            assert_abs_diff_eq!(p.inv().apply(p.apply(x)), x, epsilon = 1e-10);
+            assert_abs_diff_eq!(p.chain(p.inv()), Projective3::identity(), epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn chaining() {
+        let a = Projective3::perspective(1.0, 1.5, 0.1, 100.0);
+        let b = Projective3::from(Affine3::new(
+            Vector3::from([1.0, 2.0, -3.0]).into(),
+            Linear::from(Matrix3x3::from([
+                [1.0, 0.5, 0.0],
+                [0.0, 2.0, 0.0],
+                [0.3, 0.0, 1.0],
+            ])),
+        ));
+        let x = Vector3::from([0.3, -0.2, -4.0]);
+        assert_abs_diff_eq!(a.chain(b).apply(x), a.apply(b.apply(x)), epsilon = EPS);
+        assert_abs_diff_eq!(b.chain(a).apply(x), b.apply(a.apply(x)), epsilon = EPS);
+        assert_abs_diff_eq!(
+            a.chain(b).into_matrix(),
+            a.into_matrix().dot(b.into_matrix()),
+            epsilon = EPS
+        );
+    }
+
+    #[test]
+    fn view_projection() {
+        let eye = Vector3::from([1.0, 2.0, 3.0]);
+        let dir = Vector3::from([-1.0, -2.0, -3.0]).normalize();
+        let view = Affine3::new(
+            eye.into(),
+            Linear::look_at(dir, Vector3::from([0.0, 1.0, 0.0])),
+        )
+        .inv();
+        let proj = Projective3::perspective_zo(1.0, 1.0, 0.1, 100.0);
+        let vp = Chain::new(proj, view);
+
+        let x = Vector3::from([0.5, 0.0, -0.5]);
+        assert_abs_diff_eq!(vp.apply(x), proj.apply(view.apply(x)), epsilon = EPS);
+        assert_abs_diff_eq!(vp.inv().apply(vp.apply(x)), x, epsilon = 1e-10);
+        // Origin is in the center of the view.
+        let o = vp.apply(Vector3::zero());
+        assert_abs_diff_eq!(o.x(), 0.0, epsilon = EPS);
+        assert_abs_diff_eq!(o.y(), 0.0, epsilon = EPS);
+    }
+
+    #[test]
+    fn derivatives() {
+        let p = Projective3::perspective(1.0, 1.5, 0.1, 100.0);
+        let (x, d, h) = (
+            Vector3::from([0.3, -0.2, -4.0]),
+            Vector3::from([1.0, 0.5, -2.0]),
+            1e-6,
+        );
+        assert_abs_diff_eq!(
+            p.deriv(x, d),
+            (p.apply(x + d * h) - p.apply(x - d * h)) / (2.0 * h),
+            epsilon = 1e-6
+        );
+
+        // Normal stays orthogonal to tangent.
+        let (t, n) = (
+            Vector3::from([1.0, 1.0, 0.0]),
+            Vector3::from([1.0, -1.0, 2.0]),
+        );
+        assert_abs_diff_eq!(
+            p.apply_dir(x, t).dot(p.apply_normal(x, n)),
+            0.0,
+            epsilon = EPS
+        );
+    }
+}
@@ -1,6 +1,7 @@
 #[cfg(feature = "rand")]
 use crate::distr::{Uniform, Unit};
 use crate::{
+    batch::{Lanes, PointBatch},
     traits::Dot,
     transform::{Directional, Linear, Reorder, Shift},
     Complex, Matrix, Quaternion, Transform, Vector,
@@ -18,6 +19,7 @@ use rand_::{
 // TODO: Use partial specialization when it will be possible.
 /// Two-dimensional rotation.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
 pub struct Rotation2<T> {
     comp: Complex<T>,
 }
@@ -54,6 +56,14 @@ where
     pub fn angle(&self) -> T {
         self.comp.im().atan2(self.comp.re())
     }
+
+    /// Spherical linear interpolation between rotations.
+    ///
+    /// Rotation is performed along the shortest arc.
+    pub fn slerp(self, other: Self, t: T) -> Self {
+        let rel = Self::from_complex(self.comp.conj() * other.comp);
+        Self::from_complex(self.comp * Self::new(rel.angle() * t).comp)
+    }
 }
 
 impl<T> Transform<Vector<T, 2>> for Rotation2<T>
@@ -73,6 +83,10 @@ where
     fn apply(&self, pos: Vector<T, 2>) -> Vector<T, 2> {
         (<Vector<T, 2> as Into<Complex<T>>>::into(pos) * self.into_complex()).into()
     }
+    fn batched(&self) -> impl Fn(PointBatch<Vector<T, 2>>) -> PointBatch<Vector<T, 2>> {
+        let comp = Complex::new(Lanes::splat(self.comp.re()), Lanes::splat(self.comp.im()));
+        move |pos| (Complex::from(pos) * comp).into()
+    }
     fn deriv(&self, _pos: Vector<T, 2>, dir: Vector<T, 2>) -> Vector<T, 2> {
         self.apply(dir)
     }
@@ -107,6 +121,27 @@ where
     }
 }
 
+impl<T> Rotation2<T>
+where
+    T: Neg<Output = T> + Num + Copy,
+{
+    /// Homogeneous 3x3 matrix of the rotation.
+    pub fn to_homogeneous(&self) -> Matrix<T, 3, 3> {
+        self.to_linear().to_homogeneous()
+    }
+
+    /// Create rotation from homogeneous 3x3 matrix.
+    ///
+    /// The linear component of the matrix is assumed to be a rotation.
+    /// Returns `None` if the matrix has non-zero shift component or its last row isn't `[0, 0, 1]`.
+    pub fn from_homogeneous(mat: Matrix<T, 3, 3>) -> Option<Self> {
+        Linear::<T, 2>::from_homogeneous(mat).map(|lin| {
+            let m = lin.into_matrix();
+            Self::from_complex(Complex::new(m[(0, 0)], m[(1, 0)]))
+        })
+    }
+}
+
 #[cfg(feature = "rand")]
 impl<T> Distribution<Rotation2<T>> for Uniform
 where
@@ -114,7 +149,7 @@ where
     T: SampleUniform + Float + FloatConst + NumCast,
 {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Rotation2<T> {
-        Rotation2::new(rng.sample(&RangedUniform::new(
+        Rotation2::new(rng.sample(RangedUniform::new(
             T::zero(),
             T::from(2.0).unwrap() * T::PI(),
         )))
@@ -137,6 +172,7 @@ where
 
 /// Three-dimensional rotation.
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
 pub struct Rotation3<T> {
     quat: Quaternion<T>,
 }
@@ -179,6 +215,52 @@ where
         let (w, ax) = self.quat.into();
         T::from(2.0).unwrap() * ax.length().atan2(w)
     }
+
+    /// Create rotation from rotation vector, which direction is the axis and length is the angle of rotation.
+    ///
+    /// This is the exponential map from `so(3)` to `SO(3)`.
+    pub fn from_rotation_vector(vec: Vector<T, 3>) -> Self {
+        let half = T::from(0.5).unwrap();
+        Self::from_quaternion(Quaternion::from_scalar_and_vector3(T::zero(), vec * half).exp())
+    }
+
+    /// Rotation vector, which direction is the axis and length is the angle of rotation in `[0, π]`.
+    ///
+    /// This is the logarithmic map from `SO(3)` to `so(3)`.
+    pub fn to_rotation_vector(self) -> Vector<T, 3> {
+        let q = if self.quat.w() < T::zero() {
+            -self.quat
+        } else {
+            self.quat
+        };
+        q.ln().xyz() * T::from(2.0).unwrap()
+    }
+
+    /// Normalized linear interpolation between rotations.
+    ///
+    /// It is cheaper than `slerp` but has non-constant angular velocity.
+    pub fn nlerp(self, other: Self, t: T) -> Self {
+        Self::from_quaternion(self.quat.nlerp(other.quat, t))
+    }
+
+    /// Spherical linear interpolation between rotations.
+    ///
+    /// Rotation is performed along the shortest arc.
+    pub fn slerp(self, other: Self, t: T) -> Self {
+        Self::from_quaternion(self.quat.slerp(other.quat, t))
+    }
+
+    /// Spherical quadrangle interpolation between rotations with control points `a` and `b`.
+    ///
+    /// See [`Quaternion::squad`] for details.
+    pub fn squad(self, a: Self, b: Self, other: Self, t: T) -> Self {
+        Self::from_quaternion(self.quat.squad(a.quat, b.quat, other.quat, t))
+    }
+
+    /// Control point of `squad` interpolation for keyframe `self` with neighbours `prev` and `next`.
+    pub fn squad_control(self, prev: Self, next: Self) -> Self {
+        Self::from_quaternion(self.quat.squad_control(prev.quat, next.quat))
+    }
 }
 
 impl<T> Transform<Vector<T, 3>> for Rotation3<T>
@@ -201,6 +283,16 @@ where
         let (_, res) = qres.into();
         res
     }
+    fn batched(&self) -> impl Fn(PointBatch<Vector<T, 3>>) -> PointBatch<Vector<T, 3>> {
+        // `v + 2w (u x v) + 2u x (u x v)` is equal to `q v q*` and requires less multiplications.
+        let (w, u) = self.quat.into();
+        let (w, u) = (Lanes::splat(w), u.map(Lanes::splat));
+        move |pos| {
+            let t = u.cross(pos);
+            let t = t + t;
+            pos + t * w + u.cross(t)
+        }
+    }
     fn deriv(&self, _pos: Vector<T, 3>, dir: Vector<T, 3>) -> Vector<T, 3> {
         self.apply(dir)
     }
@@ -249,6 +341,153 @@ where
             ],
         ]))
     }
+
+    /// Create rotation from rotation matrix using Shepperd's method.
+    ///
+    /// The matrix is assumed to be orthonormal with unit determinant.
+    /// The largest quaternion component is found by comparing the trace with the diagonal elements
+    /// and the others are derived from it, so the result is stable for any rotation angle.
+    pub fn from_matrix(m: Matrix<T, 3, 3>) -> Self {
+        let one = T::one();
+        let four = T::from(4).unwrap();
+        let (m00, m11, m22) = (m[(0, 0)], m[(1, 1)], m[(2, 2)]);
+        let trace = m00 + m11 + m22;
+        let q = if trace >= m00 && trace >= m11 && trace >= m22 {
+            let s = (trace + one).sqrt() * (one + one);
+            Quaternion::new(
+                s / four,
+                (m[(2, 1)] - m[(1, 2)]) / s,
+                (m[(0, 2)] - m[(2, 0)]) / s,
+                (m[(1, 0)] - m[(0, 1)]) / s,
+            )
+        } else if m00 >= m11 && m00 >= m22 {
+            let s = (one + m00 - m11 - m22).sqrt() * (one + one);
+            Quaternion::new(
+                (m[(2, 1)] - m[(1, 2)]) / s,
+                s / four,
+                (m[(0, 1)] + m[(1, 0)]) / s,
+                (m[(0, 2)] + m[(2, 0)]) / s,
+            )
+        } else if m11 >= m22 {
+            let s = (one + m11 - m00 - m22).sqrt() * (one + one);
+            Quaternion::new(
+                (m[(0, 2)] - m[(2, 0)]) / s,
+                (m[(0, 1)] + m[(1, 0)]) / s,
+                s / four,
+                (m[(1, 2)] + m[(2, 1)]) / s,
+            )
+        } else {
+            let s = (one + m22 - m00 - m11).sqrt() * (one + one);
+            Quaternion::new(
+                (m[(1, 0)] - m[(0, 1)]) / s,
+                (m[(0, 2)] + m[(2, 0)]) / s,
+                (m[(1, 2)] + m[(2, 1)]) / s,
+                s / four,
+            )
+        };
+        Self::from_quaternion(q.normalize())
+    }
+
+    /// Create rotation from rotation matrix.
+    ///
+    /// Returns `None` if the matrix isn't a rotation, i.e. if any element of `Mᵀ·M - I` exceeds `eps` in magnitude
+    /// or the determinant isn't positive.
+    pub fn try_from_matrix(m: Matrix<T, 3, 3>, eps: T) -> Option<Self> {
+        let err = m.transpose().dot(m) - Matrix::one();
+        if !err.iter().all(|e| e.abs() <= eps)
+            || m.row(0).dot(m.row(1).cross(m.row(2))) <= T::zero()
+        {
+            return None;
+        }
+        Some(Self::from_matrix(m))
+    }
+
+    /// Create rotation from arbitrary matrix by projecting it onto `SO(3)`.
+    ///
+    /// Finds the rotation closest to the matrix in Frobenius norm using singular value decomposition.
+    /// Useful for matrices accumulated with numerical errors or obtained from noisy measurements.
+    pub fn from_matrix_approx(m: Matrix<T, 3, 3>) -> Self {
+        let svd = m.svd();
+        let (u, vt) = (svd.u(), svd.vt());
+        let r = u.dot(vt);
+        let det = r.row(0).dot(r.row(1).cross(r.row(2)));
+        let fix = Matrix::diagonal(Vector::from([T::one(), T::one(), det.signum()]));
+        Self::from_matrix(u.dot(fix).dot(vt))
+    }
+
+    /// Homogeneous 4x4 matrix of the rotation.
+    pub fn to_homogeneous(&self) -> Matrix<T, 4, 4> {
+        self.to_linear().to_homogeneous()
+    }
+
+    /// Create rotation from homogeneous 4x4 matrix.
+    ///
+    /// The linear component of the matrix is assumed to be a rotation.
+    /// Returns `None` if the matrix has non-zero shift component or its last row isn't `[0, 0, 0, 1]`.
+    pub fn from_homogeneous(mat: Matrix<T, 4, 4>) -> Option<Self> {
+        Linear::<T, 3>::from_homogeneous(mat).map(|lin| Self::from_matrix(lin.into_matrix()))
+    }
+}
+
+impl<T> Rotation3<T>
+where
+    T: Float + NumCast,
+{
+    /// Shortest-arc rotation that rotates direction of `a` to direction of `b`.
+    ///
+    /// Vectors need not be normalized, if any of them is zero then the identity rotation is returned.
+    /// If vectors are antiparallel, then the rotation by `π` around some axis orthogonal to `a` is returned.
+    pub fn rotation_between(a: Vector<T, 3>, b: Vector<T, 3>) -> Self {
+        if a.square_length() == T::zero() || b.square_length() == T::zero() {
+            return Self::identity();
+        }
+        let (a, b) = (a.normalize(), b.normalize());
+        let dot = a.dot(b);
+        if dot >= T::zero() {
+            Self::from_quaternion(
+                Quaternion::from_scalar_and_vector3(T::one() + dot, a.cross(b)).normalize(),
+            )
+        } else {
+            // Cross product of almost antiparallel vectors is inaccurate,
+            // so we rotate `a` by `π` to `-a` and then rotate `-a` to `b` around the same axis.
+            let cross = a.cross(b);
+            let cross = cross - a * a.dot(cross);
+            let axis = if cross.length() > T::epsilon() {
+                cross.normalize()
+            } else {
+                // Cross with the basis vector least aligned with `a`.
+                let i = (1..3).fold(0, |i, j| if a[j].abs() < a[i].abs() { j } else { i });
+                let e = Vector::indices().map(|j| if i == j { T::one() } else { T::zero() });
+                a.cross(e).normalize()
+            };
+            let half_turn = Quaternion::from_scalar_and_vector3(T::zero(), axis);
+            let rest =
+                Quaternion::from_scalar_and_vector3(T::one() - dot, (-a).cross(b)).normalize();
+            Self::from_quaternion(rest * half_turn)
+        }
+    }
+
+    /// Returns the rotation that rotates `-z`-axis to `dir` and `y`-axis to `up`.
+    ///
+    /// Unlike [`Linear::look_at`] `dir` need not be normalized.
+    /// Only the component of `up` orthogonal to `dir` is taken into account, so `up` must not be parallel to `dir`.
+    pub fn look_at(dir: Vector<T, 3>, up: Vector<T, 3>) -> Self {
+        let dir = dir.normalize();
+        let right = dir.cross(up).normalize();
+        let strict_up = right.cross(dir);
+        Self::from_matrix(Matrix::from([right, strict_up, -dir]).transpose())
+    }
+
+    /// Returns the rotation that rotates `z`-axis to `dir` and `y`-axis to `up`.
+    ///
+    /// This orients an object so that its front (`z`-axis) faces towards `dir`,
+    /// as opposed to [`Self::look_at`] that orients a camera looking along `-z`-axis.
+    pub fn face_towards(dir: Vector<T, 3>, up: Vector<T, 3>) -> Self {
+        let dir = dir.normalize();
+        let left = up.cross(dir).normalize();
+        let strict_up = dir.cross(left);
+        Self::from_matrix(Matrix::from([left, strict_up, dir]).transpose())
+    }
 }
 
 impl<T> Rotation3<T>
@@ -335,7 +574,7 @@ where
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Rotation3<T> {
         Rotation3::new(
             rng.sample(&Unit),
-            rng.sample(&RangedUniform::new(
+            rng.sample(RangedUniform::new(
                 T::zero(),
                 T::from(2.0).unwrap() * T::PI(),
             )),
@@ -359,7 +598,7 @@ where
 #[cfg(all(test, feature = "approx", feature = "rand"))]
 mod tests {
     use super::*;
-    use crate::{distr::Normal, prelude::*, vector::*};
+    use crate::{distr::Normal, matrix::*, prelude::*, vector::*};
     use approx::assert_abs_diff_eq;
     use rand_::SeedableRng;
     use rand_xorshift::XorShiftRng;
@@ -370,6 +609,17 @@ mod tests {
     mod r2d {
         use super::*;
 
+        #[cfg(feature = "serde")]
+        #[test]
+        fn serde() {
+            let rot = Rotation2::from_complex(Complex::new(0.6, 0.8));
+            let s = serde_json::to_string(&rot).unwrap();
+            assert_eq!(s, "[0.6,0.8]");
+            assert_eq!(serde_json::from_str::<Rotation2<f64>>(&s).unwrap(), rot);
+            assert!(serde_json::from_str::<Rotation2<f64>>("[0.6,0.6]").is_err());
+            assert!(serde_json::from_str::<Rotation2<f64>>("[0.0,0.0]").is_err());
+        }
+
         #[test]
         fn mapping() {
             let mut rng = XorShiftRng::seed_from_u64(0x2DA);
@@ -417,11 +667,57 @@ mod tests {
                 );
             }
         }
+
+        #[test]
+        fn homogeneous() {
+            let mut rng = XorShiftRng::seed_from_u64(0x2DE);
+            for _ in 0..SAMPLE_ATTEMPTS {
+                let a: Rotation2<f64> = rng.sample(&Uniform);
+                let m = a.to_homogeneous();
+                assert_eq!(m.block::<2, 2>(0, 0), a.to_linear().into_matrix());
+                assert_eq!(Rotation2::from_homogeneous(m), Some(a));
+            }
+            let mut m = Matrix3x3::<f64>::one();
+            m[(0, 2)] = 1.0;
+            assert!(Rotation2::from_homogeneous(m).is_none());
+        }
+    }
+
+    mod r2d_interp {
+        use super::*;
+        use core::f64::consts::PI;
+
+        #[test]
+        fn slerp() {
+            let mut rng = XorShiftRng::seed_from_u64(0x2DF);
+            for _ in 0..SAMPLE_ATTEMPTS {
+                let a: Rotation2<f64> = rng.sample(&Uniform);
+                let b: Rotation2<f64> = rng.sample(&Uniform);
+                assert_abs_diff_eq!(a.slerp(b, 0.0), a, epsilon = EPS);
+                assert_abs_diff_eq!(a.slerp(b, 1.0), b, epsilon = 1e-12);
+                let c = a.slerp(b, 0.5);
+                assert_abs_diff_eq!(a.inv().chain(c), c.inv().chain(b), epsilon = 1e-12);
+            }
+            // Shortest arc crosses `±π`.
+            let (a, b) = (Rotation2::new(PI - 0.1), Rotation2::new(-PI + 0.1));
+            assert_abs_diff_eq!(a.slerp(b, 0.5), Rotation2::new(PI), epsilon = EPS);
+        }
     }
 
     mod r3d {
         use super::*;
 
+        #[cfg(feature = "serde")]
+        #[test]
+        fn serde() {
+            let rot = Rotation3::from_quaternion(Quaternion::new(0.5, 0.5, -0.5, 0.5));
+            let s = serde_json::to_string(&rot).unwrap();
+            assert_eq!(s, "[0.5,0.5,-0.5,0.5]");
+            assert_eq!(serde_json::from_str::<Rotation3<f64>>(&s).unwrap(), rot);
+            assert!(serde_json::from_str::<Rotation3<f64>>("[1.0,0.5,0.0,0.0]").is_err());
+            assert!(serde_json::from_str::<Rotation3<f32>>("[0.0,0.0,0.0,0.0]").is_err());
+        }
+
         #[test]
         fn mapping() {
             let mut rng = XorShiftRng::seed_from_u64(0x3DA);
@@ -475,6 +771,185 @@ mod tests {
             }
         }
 
+        #[test]
+        fn homogeneous() {
+            let mut rng = XorShiftRng::seed_from_u64(0x2DE);
+            for _ in 0..SAMPLE_ATTEMPTS {
+                let a: Rotation3<f64> = rng.sample(&Uniform);
+                let x: Vector3<f64> = rng.sample(&Normal);
+                let m = a.to_homogeneous();
+                assert_eq!(m.block::<3, 3>(0, 0), a.to_linear().into_matrix());
+                let b = Rotation3::from_homogeneous(m).unwrap();
+                assert_abs_diff_eq!(b.apply(x), a.apply(x), epsilon = 1e-12);
+            }
+            let mut m = Matrix4x4::<f64>::one();
+            m[(3, 0)] = 1.0;
+            assert!(Rotation3::from_homogeneous(m).is_none());
+        }
+
+        #[test]
+        fn from_matrix() {
+            use core::f64::consts::PI;
+            let mut rng = XorShiftRng::seed_from_u64(0x2E1);
+            for _ in 0..SAMPLE_ATTEMPTS {
+                let a: Rotation3<f64> = rng.sample(&Uniform);
+                let x: Vector3<f64> = rng.sample(&Normal);
+                let m = a.to_linear().into_matrix();
+                let b = Rotation3::from_matrix(m);
+                assert_abs_diff_eq!(b.apply(x), a.apply(x), epsilon = 1e-12);
+                let c = Rotation3::try_from_matrix(m, 1e-12).unwrap();
+                assert_abs_diff_eq!(c.apply(x), a.apply(x), epsilon = 1e-12);
+            }
+            // Angles close to `π` where the trace is near `-1`.
+            for axis in [
+                [1.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [0.0, 0.0, 1.0],
+                [1.0, -2.0, 3.0],
+            ] {
+                let axis = Vector3::from(axis).normalize();
+                let a = Rotation3::new(axis, PI - 1e-9);
+                let b = Rotation3::from_matrix(a.to_linear().into_matrix());
+                assert_abs_diff_eq!(b.apply(axis), axis, epsilon = 1e-12);
+                assert_abs_diff_eq!(b.angle().cos(), -1.0, epsilon = 1e-12);
+            }
+            // Positive trace with a larger diagonal element, the vector part is the largest.
+            for axis in [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]] {
+                let axis = Vector3::from(axis);
+                let a = Rotation3::new(axis, 2.0);
+                let b = Rotation3::from_matrix(a.to_linear().into_matrix());
+                assert_abs_diff_eq!(b, a, epsilon = 1e-12);
+            }
+        }
+
+        #[test]
+        fn try_from_matrix() {
+            let m = Rotation3::new(Vector3::from([0.0, 0.0, 1.0]), 0.3)
+                .to_linear()
+                .into_matrix();
+            assert!(Rotation3::try_from_matrix(m, 1e-12).is_some());
+            assert!(Rotation3::try_from_matrix(m * 1.01, 1e-12).is_none());
+            assert!(Rotation3::try_from_matrix(-m, 1e-12).is_none());
+            assert!(Rotation3::try_from_matrix(Matrix3x3::from([[0.0; 3]; 3]), 1e-12).is_none());
+            assert!(
+                Rotation3::try_from_matrix(Matrix3x3::from([[f64::NAN; 3]; 3]), 1e-12).is_none()
+            );
+        }
+
+        #[test]
+        fn from_matrix_approx() {
+            let mut rng = XorShiftRng::seed_from_u64(0x2E2);
+            for _ in 0..SAMPLE_ATTEMPTS {
+                let a: Rotation3<f64> = rng.sample(&Uniform);
+                let x: Vector3<f64> = rng.sample(&Normal);
+                let m = a.to_linear().into_matrix();
+                let b = Rotation3::from_matrix_approx(m);
+                assert_abs_diff_eq!(b.apply(x), a.apply(x), epsilon = 1e-12);
+
+                let noise: Matrix3x3<f64> = rng.sample(&Normal);
+                let c = Rotation3::from_matrix_approx(m + noise * 1e-3);
+                assert_abs_diff_eq!(c.apply(x), a.apply(x), epsilon = 1e-2 * x.length());
+                let n = c.to_linear().into_matrix();
+                assert!(Rotation3::try_from_matrix(n, 1e-12).is_some());
+            }
+            // Reflection is projected to a proper rotation.
+            let r =
+                Rotation3::from_matrix_approx(Matrix3x3::diagonal(Vector3::from([1.0, 1.0, -1.0])));
+            let n = r.to_linear().into_matrix();
+            assert!(Rotation3::try_from_matrix(n, 1e-12).is_some());
+        }
+
+        #[test]
+        fn rotation_vector() {
+            use core::f64::consts::PI;
+            let mut rng = XorShiftRng::seed_from_u64(0x2E0);
+            for _ in 0..SAMPLE_ATTEMPTS {
+                let axis: Vector3<f64> = rng.sample(&Unit);
+                let angle = rng.sample(RangedUniform::new(0.0, PI));
+                let x: Vector3<f64> = rng.sample(&Normal);
+                let r = Rotation3::from_rotation_vector(axis * angle);
+                assert_abs_diff_eq!(
+                    r.apply(x),
+                    Rotation3::new(axis, angle).apply(x),
+                    epsilon = 1e-12
+                );
+                assert_abs_diff_eq!(r.to_rotation_vector(), axis * angle, epsilon = 1e-9);
+                // Double cover.
+                let r = Rotation3::from_quaternion(-r.into_quaternion());
+                assert_abs_diff_eq!(r.to_rotation_vector(), axis * angle, epsilon = 1e-9);
+            }
+            let small = Vector3::from([1e-20, -2e-20, 0.0]);
+            assert_abs_diff_eq!(
+                Rotation3::from_rotation_vector(small).to_rotation_vector(),
+                small
+            );
+            assert_eq!(
+                Rotation3::<f64>::identity().to_rotation_vector(),
+                Vector3::zero()
+            );
+        }
+
+        #[test]
+        fn angular_velocity_integration() {
+            let omega = Vector3::from([0.3, -1.2, 2.0]);
+            let dt = 0.01;
+            let step = Rotation3::from_rotation_vector(omega * dt);
+            let r = (0..100).fold(Rotation3::identity(), |r, _| step.chain(r));
+            assert_abs_diff_eq!(r, Rotation3::from_rotation_vector(omega), epsilon = 1e-12);
+        }
+
+        #[test]
+        fn interpolation() {
+            let mut rng = XorShiftRng::seed_from_u64(0x2DF);
+            for _ in 0..SAMPLE_ATTEMPTS {
+                let a: Rotation3<f64> = rng.sample(&Uniform);
+                let b: Rotation3<f64> = rng.sample(&Uniform);
+                let x: Vector3<f64> = rng.sample(&Normal);
+                let c = a.slerp(b, 0.5);
+                assert_abs_diff_eq!(
+                    a.inv().chain(c).apply(x),
+                    c.inv().chain(b).apply(x),
+                    epsilon = 1e-9
+                );
+                assert_abs_diff_eq!(a.slerp(b, 1.0).apply(x), b.apply(x), epsilon = 1e-9);
+                assert_abs_diff_eq!(a.nlerp(b, 1.0).apply(x), b.apply(x), epsilon = 1e-9);
+            }
+        }
+
+        #[test]
+        fn squad() {
+            let mut rng = XorShiftRng::seed_from_u64(0x2E0);
+            for _ in 0..SAMPLE_ATTEMPTS {
+                let r: [Rotation3<f64>; 4] = [
+                    rng.sample(&Uniform),
+                    rng.sample(&Uniform),
+                    rng.sample(&Uniform),
+                    rng.sample(&Uniform),
+                ];
+                let x: Vector3<f64> = rng.sample(&Normal);
+                let s1 = r[1].squad_control(r[0], r[2]);
+                let s2 = r[2].squad_control(r[1], r[3]);
+                let c = |t| r[1].squad(s1, s2, r[2], t);
+                assert_abs_diff_eq!(c(0.0).apply(x), r[1].apply(x), epsilon = 1e-9);
+                assert_abs_diff_eq!(c(1.0).apply(x), r[2].apply(x), epsilon = 1e-9);
+                assert_abs_diff_eq!(c(0.5).into_quaternion().norm(), 1.0, epsilon = 1e-12);
+
+                // Keyframes evenly spaced around the same axis give uniform rotation.
+                let (axis, step): (Vector3<f64>, f64) = (rng.sample(&Unit), 0.4);
+                let r = [0.0, 1.0, 2.0, 3.0].map(|i| Rotation3::new(axis, i * step));
+                let s1 = r[1].squad_control(r[0], r[2]);
+                let s2 = r[2].squad_control(r[1], r[3]);
+                let c = |t| r[1].squad(s1, s2, r[2], t);
+                assert_abs_diff_eq!(c(0.0).apply(x), r[1].apply(x), epsilon = 1e-9);
+                assert_abs_diff_eq!(
+                    c(0.5).apply(x),
+                    Rotation3::new(axis, 1.5 * step).apply(x),
+                    epsilon = 1e-9
+                );
+                assert_abs_diff_eq!(c(1.0).apply(x), r[2].apply(x), epsilon = 1e-9);
+            }
+        }
+
         #[test]
         fn look_to_the_direction() {
             const EPS: f64 = 1e-14;
@@ -487,5 +962,83 @@ mod tests {
                 assert_abs_diff_eq!(m.apply(Vector::from([0.0, 0.0, -1.0])), d, epsilon = EPS);
             }
         }
+
+        #[test]
+        fn rotation_between() {
+            let mut rng = XorShiftRng::seed_from_u64(0xBED);
+            for _ in 0..SAMPLE_ATTEMPTS {
+                let a: Vector3<f64> = rng.sample(&Normal);
+                let b: Vector3<f64> = rng.sample(&Normal);
+                let r = Rotation3::rotation_between(a, b);
+                assert_abs_diff_eq!(r.into_quaternion().norm(), 1.0, epsilon = 1e-12);
+                assert_abs_diff_eq!(r.apply(a.normalize()), b.normalize(), epsilon = 1e-12);
+                // Shortest arc rotates around the axis orthogonal to both vectors.
+                assert_abs_diff_eq!(r.axis().dot(a), 0.0, epsilon = 1e-9);
+                assert_abs_diff_eq!(r.axis().dot(b), 0.0, epsilon = 1e-9);
+
+                let r = Rotation3::rotation_between(a, -a * 2.0);
+                assert_abs_diff_eq!(r.apply(a), -a, epsilon = 1e-12);
+                let c = -a + b * 1e-9;
+                let r = Rotation3::rotation_between(a, c);
+                assert_abs_diff_eq!(r.apply(a.normalize()), c.normalize(), epsilon = 1e-12);
+            }
+            for a in [[1.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, 3.0]] {
+                let a = Vector3::from(a);
+                let r = Rotation3::rotation_between(a, -a);
+                assert_abs_diff_eq!(r.apply(a), -a, epsilon = EPS);
+                assert_abs_diff_eq!(
+                    Rotation3::rotation_between(a, a),
+                    Rotation3::identity(),
+                    epsilon = EPS
+                );
+                assert_eq!(
+                    Rotation3::rotation_between(a, Vector3::zero()),
+                    Rotation3::identity()
+                );
+                assert_eq!(
+                    Rotation3::rotation_between(Vector3::zero(), a),
+                    Rotation3::identity()
+                );
+            }
+            // Non-finite input gives non-finite result instead of panic.
+            let r = Rotation3::rotation_between(
+                Vector3::fill(f64::NAN),
+                Vector3::from([1.0, 0.0, 0.0]),
+            );
+            assert!(r.into_quaternion().w().is_nan());
+        }
+
+        #[test]
+        fn look_at() {
+            let mut rng = XorShiftRng::seed_from_u64(0xBEE);
+            for _ in 0..SAMPLE_ATTEMPTS {
+                let d: Vector3<f64> = rng.sample(&Normal);
+                let u: Vector3<f64> = rng.sample(&Normal);
+                let x: Vector3<f64> = rng.sample(&Normal);
+                let r = Rotation3::look_at(d, u);
+                assert_abs_diff_eq!(r.into_quaternion().norm(), 1.0, epsilon = 1e-12);
+                assert_abs_diff_eq!(
+                    r.apply(Vector3::from([0.0, 0.0, -1.0])),
+                    d.normalize(),
+                    epsilon = 1e-12
+                );
+                let up = r.apply(Vector3::from([0.0, 1.0, 0.0]));
+                assert_abs_diff_eq!(up.dot(d), 0.0, epsilon = 1e-9);
+                assert!(up.dot(u) > 0.0);
+                let (dn, un) = (
+                    d.normalize(),
+                    (u - d * (u.dot(d) / d.square_length())).normalize(),
+                );
+                assert_abs_diff_eq!(r.apply(x), Linear::look_at(dn, un).apply(x), epsilon = 1e-9);
+
+                let f = Rotation3::face_towards(d, u);
+                assert_abs_diff_eq!(
+                    f.apply(Vector3::from([0.0, 0.0, 1.0])),
+                    d.normalize(),
+                    epsilon = 1e-12
+                );
+                assert_abs_diff_eq!(f.apply(Vector3::from([0.0, 1.0, 0.0])), up, epsilon = 1e-9);
+            }
+        }
     }
 }
@@ -1,18 +1,24 @@
 #[cfg(feature = "rand")]
 use crate::distr::Normal;
 use crate::{
-    transform::{Reorder, Shift, Directional},
-    Transform, Vector,
+    batch::{Lanes, PointBatch},
+    transform::{Directional, Reorder, Shift},
+    Matrix, Transform, Vector,
 };
 #[cfg(feature = "approx")]
 use approx::{abs_diff_eq, AbsDiffEq};
-use num_traits::{Num, Inv};
+use num_traits::{Inv, Num, One, Zero};
 #[cfg(feature = "rand")]
 use rand_::{distributions::Distribution, Rng};
 
 /// Scale transformation.
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Scale<T> {
     mag: T,
 }
@@ -30,6 +36,37 @@ impl<T> From<T> for Scale<T> {
         Self::from_mag(mag)
     }
 }
+impl<T> Scale<T>
+where
+    T: Zero + One + PartialEq + Copy,
+{
+    /// Homogeneous `L`x`L` matrix of the transformation of `L - 1`-dimensional space.
+    pub fn to_homogeneous<const L: usize>(&self) -> Matrix<T, L, L> {
+        Matrix::indices().map(|(i, j)| {
+            if i != j {
+                T::zero()
+            } else if i + 1 < L {
+                self.mag
+            } else {
+                T::one()
+            }
+        })
+    }
+
+    /// Create transformation from homogeneous `L`x`L` matrix.
+    ///
+    /// Returns `None` if the matrix isn't a uniform scaling.
+    pub fn from_homogeneous<const L: usize>(mat: Matrix<T, L, L>) -> Option<Self> {
+        assert!(L > 0);
+        let scale = Self::from_mag(if L > 1 { mat[(0, 0)] } else { T::one() });
+        if scale.to_homogeneous() == mat {
+            Some(scale)
+        } else {
+            None
+        }
+    }
+}
+
 /*
 impl<T> From<Scale<T>> for T {
     fn from(scale: Scale<T>) -> T {
@@ -46,16 +83,24 @@ where
         Self { mag: T::one() }
     }
     fn inv(self) -> Self {
-        Self { mag: self.mag.inv() }
+        Self {
+            mag: self.mag.inv(),
+        }
     }
     fn apply(&self, pos: Vector<T, N>) -> Vector<T, N> {
         pos * self.mag
     }
+    fn batched(&self) -> impl Fn(PointBatch<Vector<T, N>>) -> PointBatch<Vector<T, N>> {
+        let mag = Lanes::splat(self.mag);
+        move |pos| pos * mag
+    }
     fn deriv(&self, _pos: Vector<T, N>, dir: Vector<T, N>) -> Vector<T, N> {
         self.apply(dir)
     }
     fn chain(self, other: Self) -> Self {
-        Self { mag: self.mag * other.mag }
+        Self {
+            mag: self.mag * other.mag,
+        }
     }
 }
 
@@ -114,3 +159,16 @@ where
         (self.apply(other.into_vector()).into(), self)
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde() {
+        let scale = Scale::from_mag(2.5);
+        let s = serde_json::to_string(&scale).unwrap();
+        assert_eq!(s, "2.5");
+        assert_eq!(serde_json::from_str::<Scale<f64>>(&s).unwrap(), scale);
+    }
+}
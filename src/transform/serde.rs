@@ -0,0 +1,47 @@
+use crate::{
+    transform::{Homogeneous, Rotation2, Rotation3},
+    Complex, Matrix, Quaternion,
+};
+use num_traits::Float;
+use serde::{de::Error, Deserialize, Deserializer};
+
+/// Whether the square norm doesn't differ from one by more than the square root of machine epsilon.
+fn is_unit<T: Float>(norm_sqr: T) -> bool {
+    (norm_sqr - T::one()).abs() <= T::epsilon().sqrt()
+}
+
+/// Deserialization fails if `L != N + 1`.
+impl<'de, T: Deserialize<'de>, const N: usize, const L: usize> Deserialize<'de>
+    for Homogeneous<T, N, L>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if L != N + 1 {
+            return Err(D::Error::custom(
+                "Homogeneous matrix must be one row and one column larger",
+            ));
+        }
+        Matrix::<T, L, L>::deserialize(deserializer).map(Self::from_matrix)
+    }
+}
+
+/// Deserialization fails if the complex number isn't unit.
+impl<'de, T: Deserialize<'de> + Float> Deserialize<'de> for Rotation2<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let comp = Complex::<T>::deserialize(deserializer)?;
+        if !is_unit(comp.norm_sqr()) {
+            return Err(D::Error::custom("Rotation must be a unit complex number"));
+        }
+        Ok(Self::from_complex(comp))
+    }
+}
+
+/// Deserialization fails if the quaternion isn't unit.
+impl<'de, T: Deserialize<'de> + Float> Deserialize<'de> for Rotation3<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let quat = Quaternion::<T>::deserialize(deserializer)?;
+        if !is_unit(quat.norm_sqr()) {
+            return Err(D::Error::custom("Rotation must be a unit quaternion"));
+        }
+        Ok(Self::from_quaternion(quat))
+    }
+}
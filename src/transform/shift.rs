@@ -1,16 +1,28 @@
 #[cfg(feature = "rand")]
 use crate::distr::Normal;
-use crate::{Transform, Vector, transform::Directional};
+use crate::{
+    batch::{Lanes, PointBatch},
+    transform::{
+        homogeneous::{join_homogeneous, split_homogeneous},
+        Directional,
+    },
+    Matrix, Transform, Vector,
+};
 #[cfg(feature = "approx")]
 use approx::{abs_diff_eq, AbsDiffEq};
 use core::ops::Neg;
-use num_traits::{Num, Zero};
+use num_traits::{Num, One, Zero};
 #[cfg(feature = "rand")]
 use rand_::{distributions::Distribution, Rng};
 
 /// Shift transformation.
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Shift<T, const N: usize> {
     pos: Vector<T, N>,
 }
@@ -27,6 +39,29 @@ impl<T, const N: usize> Shift<T, N> {
         self.pos
     }
 }
+impl<T, const N: usize> Shift<T, N>
+where
+    T: Zero + One + PartialEq + Copy,
+{
+    /// Homogeneous `L`x`L` matrix of the transformation, where `L = N + 1`.
+    pub fn to_homogeneous<const L: usize>(&self) -> Matrix<T, L, L> {
+        join_homogeneous(Matrix::one(), self.pos)
+    }
+
+    /// Create transformation from homogeneous `L`x`L` matrix, where `L = N + 1`.
+    ///
+    /// Returns `None` if the linear component of the matrix isn't identity or its last row isn't `[0, ..., 0, 1]`.
+    pub fn from_homogeneous<const L: usize>(mat: Matrix<T, L, L>) -> Option<Self> {
+        split_homogeneous(mat).and_then(|(lin, shift)| {
+            if lin == Matrix::one() {
+                Some(Self::from_vector(shift))
+            } else {
+                None
+            }
+        })
+    }
+}
+
 impl<T, const N: usize> From<Vector<T, N>> for Shift<T, N> {
     fn from(pos: Vector<T, N>) -> Self {
         Self::from_vector(pos)
@@ -53,6 +88,10 @@ where
     fn apply(&self, pos: Vector<T, N>) -> Vector<T, N> {
         pos + self.pos
     }
+    fn batched(&self) -> impl Fn(PointBatch<Vector<T, N>>) -> PointBatch<Vector<T, N>> {
+        let shift = self.pos.map(Lanes::splat);
+        move |pos| pos + shift
+    }
     fn deriv(&self, _pos: Vector<T, N>, dir: Vector<T, N>) -> Vector<T, N> {
         dir
     }
@@ -65,7 +104,7 @@ where
 
 impl<T, const N: usize> Directional<Vector<T, N>> for Shift<T, N>
 where
-    Self: Transform<Vector<T, N>>
+    Self: Transform<Vector<T, N>>,
 {
     fn apply_dir(&self, _: Vector<T, N>, dir: Vector<T, N>) -> Vector<T, N> {
         dir
@@ -98,3 +137,16 @@ where
         abs_diff_eq!(self.pos, other.pos, epsilon = epsilon)
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde() {
+        let shift = Shift::from(Vector::from([0.1, -2.0, 1.0 / 3.0]));
+        let s = serde_json::to_string(&shift).unwrap();
+        assert_eq!(s, "[0.1,-2.0,0.3333333333333333]");
+        assert_eq!(serde_json::from_str::<Shift<f64, 3>>(&s).unwrap(), shift);
+    }
+}
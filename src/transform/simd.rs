@@ -0,0 +1,59 @@
+use crate::{transform::Rotation3, Vector};
+use wide::{f32x4, f64x4};
+
+macro_rules! impl_simd {
+    ($T:ident, $S:ident) => {
+        impl Rotation3<$T> {
+            /// Rotate vector using SIMD instructions.
+            ///
+            /// Computes `v + w t + u x t` where `t = 2 u x v`, that is equal to `q v q*` for unit quaternion `q = w + u`.
+            #[inline]
+            pub fn simd_apply(&self, pos: Vector<$T, 3>) -> Vector<$T, 3> {
+                // Cross product of three-dimensional vectors stored in the first three lanes.
+                let cross = |a: [$T; 4], b: [$T; 4]| {
+                    $S::new([a[1], a[2], a[0], 0.0]) * $S::new([b[2], b[0], b[1], 0.0])
+                        - $S::new([a[2], a[0], a[1], 0.0]) * $S::new([b[1], b[2], b[0], 0.0])
+                };
+                let [w, x, y, z] = self.into_quaternion().into_vector().into_array();
+                let u = [x, y, z, 0.0];
+                let v = [pos.x(), pos.y(), pos.z(), 0.0];
+                let t = cross(u, v) * $S::splat(2.0);
+                let r = t.mul_add($S::splat(w), $S::new(v)) + cross(u, t.to_array());
+                let [rx, ry, rz, _] = r.to_array();
+                Vector::from([rx, ry, rz])
+            }
+        }
+    };
+}
+
+impl_simd!(f32, f32x4);
+impl_simd!(f64, f64x4);
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use crate::{
+        distr::{Normal, Uniform},
+        transform::Rotation3,
+        Quaternion, Transform, Vector,
+    };
+    use approx::assert_abs_diff_eq;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+
+    #[test]
+    fn rotation3_apply() {
+        let mut rng = XorShiftRng::seed_from_u64(0xBEEF);
+        for _ in 0..SAMPLE_ATTEMPTS {
+            let r: Rotation3<f64> = rng.sample(&Uniform);
+            let v: Vector<f64, 3> = rng.sample(&Normal);
+            assert_abs_diff_eq!(r.simd_apply(v), r.apply(v), epsilon = 1e-12);
+            let r = Rotation3::from_quaternion(Quaternion::from_vector(
+                r.into_quaternion().into_vector().map(|x| x as f32),
+            ));
+            let v = v.map(|x| x as f32);
+            assert_abs_diff_eq!(r.simd_apply(v), r.apply(v), epsilon = 1e-4);
+        }
+    }
+}
@@ -0,0 +1,536 @@
+#[cfg(feature = "rand")]
+use crate::distr::{Normal, Uniform};
+use crate::{
+    batch::PointBatch,
+    matrix::DetStrategy,
+    traits::{Dot, Outer},
+    transform::{
+        Affine, Directional, Isometry, Isometry2, Isometry3, Reorder, Rotation2, Rotation3, Scale,
+        Shift,
+    },
+    Complex, Matrix, Transform, Vector,
+};
+#[cfg(feature = "approx")]
+use approx::{abs_diff_eq, AbsDiffEq};
+use core::ops::Neg;
+use num_traits::{Float, Inv, Num, NumCast, One, Zero};
+#[cfg(feature = "rand")]
+use rand_::{distributions::Distribution, Rng};
+
+/// Similarity transformation, i.e. rotation followed by uniform scaling and then by shift.
+///
+/// Transformations are applied in the following order: `S(K(R(x)))`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Similarity<T, R, const N: usize> {
+    shift: Shift<T, N>,
+    scale: Scale<T>,
+    rotation: R,
+}
+
+pub type Similarity2<T> = Similarity<T, Rotation2<T>, 2>;
+pub type Similarity3<T> = Similarity<T, Rotation3<T>, 3>;
+
+impl<T, R, const N: usize> Similarity<T, R, N> {
+    /// Construct similarity from shift, scale and rotation components.
+    pub fn new(shift: Shift<T, N>, scale: Scale<T>, rotation: R) -> Self {
+        Self {
+            shift,
+            scale,
+            rotation,
+        }
+    }
+
+    /// Split into shift, scale and rotation components.
+    pub fn split(self) -> (Shift<T, N>, Scale<T>, R) {
+        (self.shift, self.scale, self.rotation)
+    }
+}
+
+impl<T, R, const N: usize> Similarity<T, R, N>
+where
+    T: Copy,
+    R: Copy,
+{
+    /// Shift component of the transformation.
+    pub fn shift(&self) -> Shift<T, N> {
+        self.shift
+    }
+
+    /// Scale component of the transformation.
+    pub fn scale(&self) -> Scale<T> {
+        self.scale
+    }
+
+    /// Rotation component of the transformation.
+    pub fn rotation(&self) -> R {
+        self.rotation
+    }
+}
+
+impl<T, R, const N: usize> From<Isometry<T, R, N>> for Similarity<T, R, N>
+where
+    T: One,
+{
+    fn from(iso: Isometry<T, R, N>) -> Self {
+        let (shift, rotation) = iso.split();
+        Self::new(shift, Scale::from_mag(T::one()), rotation)
+    }
+}
+
+impl<T, R, const N: usize> Transform<Vector<T, N>> for Similarity<T, R, N>
+where
+    R: Transform<Vector<T, N>> + Copy,
+    T: Neg<Output = T> + Num + Inv<Output = T> + Copy,
+{
+    fn identity() -> Self {
+        Self::new(Shift::identity(), Scale::from_mag(T::one()), R::identity())
+    }
+    fn inv(self) -> Self {
+        let scale = Scale::from_mag(self.scale.into_mag().inv());
+        let rotation = self.rotation.inv();
+        let shift = scale.apply(rotation.apply(self.shift.inv().into_vector()));
+        Self::new(shift.into(), scale, rotation)
+    }
+    fn apply(&self, pos: Vector<T, N>) -> Vector<T, N> {
+        self.shift.apply(self.scale.apply(self.rotation.apply(pos)))
+    }
+    fn batched(&self) -> impl Fn(PointBatch<Vector<T, N>>) -> PointBatch<Vector<T, N>> {
+        let (rotation, scale, shift) = (
+            self.rotation.batched(),
+            self.scale.batched(),
+            self.shift.batched(),
+        );
+        move |pos| shift(scale(rotation(pos)))
+    }
+    fn deriv(&self, pos: Vector<T, N>, dir: Vector<T, N>) -> Vector<T, N> {
+        self.scale.apply(self.rotation.deriv(pos, dir))
+    }
+    fn chain(self, other: Self) -> Self {
+        let shift = self
+            .scale
+            .apply(self.rotation.apply(other.shift.into_vector()));
+        Self::new(
+            self.shift.chain(shift.into()),
+            Scale::from_mag(self.scale.into_mag() * other.scale.into_mag()),
+            self.rotation.chain(other.rotation),
+        )
+    }
+}
+
+impl<T, R, const N: usize> Directional<Vector<T, N>> for Similarity<T, R, N>
+where
+    R: Directional<Vector<T, N>> + Copy,
+    Self: Transform<Vector<T, N>>,
+{
+    fn apply_dir(&self, pos: Vector<T, N>, dir: Vector<T, N>) -> Vector<T, N> {
+        self.rotation.apply_dir(pos, dir)
+    }
+    fn apply_normal(&self, pos: Vector<T, N>, normal: Vector<T, N>) -> Vector<T, N> {
+        self.rotation.apply_normal(pos, normal)
+    }
+}
+
+impl<T, R, const N: usize> Reorder<Shift<T, N>, Vector<T, N>> for Similarity<T, R, N>
+where
+    Self: Transform<Vector<T, N>>,
+    R: Transform<Vector<T, N>>,
+    Shift<T, N>: Transform<Vector<T, N>>,
+    Scale<T>: Transform<Vector<T, N>>,
+{
+    fn reorder(self, other: Shift<T, N>) -> (Shift<T, N>, Self) {
+        let shift = self.scale.apply(self.rotation.apply(other.into_vector()));
+        (shift.into(), self)
+    }
+}
+impl<T, R, const N: usize> Reorder<Similarity<T, R, N>, Vector<T, N>> for Shift<T, N>
+where
+    Self: Transform<Vector<T, N>>,
+    Similarity<T, R, N>: Transform<Vector<T, N>>,
+    R: Transform<Vector<T, N>> + Copy,
+    Scale<T>: Transform<Vector<T, N>> + Copy,
+{
+    fn reorder(self, other: Similarity<T, R, N>) -> (Similarity<T, R, N>, Self) {
+        let shift = other
+            .rotation
+            .inv()
+            .apply(other.scale.inv().apply(self.into_vector()));
+        (other, shift.into())
+    }
+}
+
+impl<T, R, const N: usize> Reorder<Scale<T>, Vector<T, N>> for Similarity<T, R, N>
+where
+    Self: Transform<Vector<T, N>>,
+    Scale<T>: Transform<Vector<T, N>> + Copy,
+{
+    fn reorder(self, other: Scale<T>) -> (Scale<T>, Self) {
+        let shift = other.inv().apply(self.shift.into_vector());
+        (other, Self::new(shift.into(), self.scale, self.rotation))
+    }
+}
+impl<T, R, const N: usize> Reorder<Similarity<T, R, N>, Vector<T, N>> for Scale<T>
+where
+    Self: Transform<Vector<T, N>> + Copy,
+    Similarity<T, R, N>: Transform<Vector<T, N>>,
+{
+    fn reorder(self, other: Similarity<T, R, N>) -> (Similarity<T, R, N>, Self) {
+        let shift = self.apply(other.shift.into_vector());
+        (
+            Similarity::new(shift.into(), other.scale, other.rotation),
+            self,
+        )
+    }
+}
+
+/// Least-squares estimation of similarity transformation using Umeyama's method.
+///
+/// Returns scale, rotation matrix and shift, or `None` if there are no points or all source points coincide.
+fn umeyama<T, const N: usize>(
+    src: &[Vector<T, N>],
+    dst: &[Vector<T, N>],
+    with_scale: bool,
+) -> Option<(T, Matrix<T, N, N>, Vector<T, N>)>
+where
+    T: Float + DetStrategy,
+{
+    assert_eq!(src.len(), dst.len(), "Point sets must have the same length");
+    if src.is_empty() {
+        return None;
+    }
+    let count = T::from(src.len()).unwrap();
+    let mean = |points: &[Vector<T, N>]| points.iter().fold(Vector::zero(), |a, p| a + *p) / count;
+    let (src_mean, dst_mean) = (mean(src), mean(dst));
+
+    let mut var = T::zero();
+    let mut cov = Matrix::<T, N, N>::zero();
+    for (x, y) in src.iter().zip(dst.iter()) {
+        let (x, y) = (*x - src_mean, *y - dst_mean);
+        var = var + x.square_length();
+        cov = cov + y.outer(x);
+    }
+    let (var, cov) = (var / count, cov / count);
+    if var <= T::zero() {
+        return None;
+    }
+
+    let svd = cov.svd();
+    let (u, vt) = (svd.u(), svd.vt());
+    // Choose proper rotation if the best orthogonal fit is a reflection.
+    let sign = Vector::<usize, N>::indices().map(|i| {
+        if i + 1 == N && u.det() * vt.det() < T::zero() {
+            -T::one()
+        } else {
+            T::one()
+        }
+    });
+    let rot = u.dot(Matrix::diagonal(sign)).dot(vt);
+    let scale = if with_scale {
+        (svd.singular_values() * sign).sum() / var
+    } else {
+        T::one()
+    };
+    Some((scale, rot, dst_mean - rot.dot(src_mean) * scale))
+}
+
+impl<T> Similarity2<T>
+where
+    T: Float + NumCast,
+{
+    /// Affine transformation equal to the similarity.
+    pub fn to_affine(self) -> Affine<T, 2> {
+        let lin = self.rotation.to_linear().into_matrix() * self.scale.into_mag();
+        Affine::new(self.shift, lin.into())
+    }
+
+    /// Homogeneous 3x3 matrix of the transformation.
+    pub fn to_homogeneous(&self) -> Matrix<T, 3, 3> {
+        self.to_affine().to_homogeneous()
+    }
+}
+
+impl<T> Similarity2<T>
+where
+    T: Float + DetStrategy,
+{
+    /// Similarity transformation that best maps `src` points to corresponding `dst` points in the least-squares sense.
+    ///
+    /// Uses Umeyama's method. The result is always a proper similarity without reflection.
+    /// Returns `None` if point sets are empty or all `src` points coincide.
+    ///
+    /// Panics if point sets have different lengths.
+    pub fn umeyama(src: &[Vector<T, 2>], dst: &[Vector<T, 2>]) -> Option<Self> {
+        umeyama(src, dst, true).map(|(scale, rot, shift)| {
+            let rotation = Rotation2::from_complex(Complex::new(rot[(0, 0)], rot[(1, 0)]));
+            Self::new(shift.into(), scale.into(), rotation)
+        })
+    }
+}
+
+impl<T> Similarity3<T>
+where
+    T: Float + NumCast,
+{
+    /// Affine transformation equal to the similarity.
+    pub fn to_affine(self) -> Affine<T, 3> {
+        let lin = self.rotation.to_linear().into_matrix() * self.scale.into_mag();
+        Affine::new(self.shift, lin.into())
+    }
+
+    /// Homogeneous 4x4 matrix of the transformation.
+    pub fn to_homogeneous(&self) -> Matrix<T, 4, 4> {
+        self.to_affine().to_homogeneous()
+    }
+}
+
+impl<T> Similarity3<T>
+where
+    T: Float + DetStrategy,
+{
+    /// Similarity transformation that best maps `src` points to corresponding `dst` points in the least-squares sense.
+    ///
+    /// Uses Umeyama's method. The result is always a proper similarity without reflection.
+    /// Returns `None` if point sets are empty or all `src` points coincide.
+    ///
+    /// Panics if point sets have different lengths.
+    pub fn umeyama(src: &[Vector<T, 3>], dst: &[Vector<T, 3>]) -> Option<Self> {
+        umeyama(src, dst, true).map(|(scale, rot, shift)| {
+            Self::new(shift.into(), scale.into(), Rotation3::from_matrix(rot))
+        })
+    }
+}
+
+impl<T> Isometry2<T>
+where
+    T: Float + DetStrategy,
+{
+    /// Rigid transformation that best maps `src` points to corresponding `dst` points in the least-squares sense.
+    ///
+    /// Uses Umeyama's method without scaling (also known as Kabsch algorithm).
+    /// Returns `None` if point sets are empty or all `src` points coincide.
+    ///
+    /// Panics if point sets have different lengths.
+    pub fn umeyama(src: &[Vector<T, 2>], dst: &[Vector<T, 2>]) -> Option<Self> {
+        umeyama(src, dst, false).map(|(_, rot, shift)| {
+            let rotation = Rotation2::from_complex(Complex::new(rot[(0, 0)], rot[(1, 0)]));
+            Self::new(shift.into(), rotation)
+        })
+    }
+}
+
+impl<T> Isometry3<T>
+where
+    T: Float + DetStrategy,
+{
+    /// Rigid transformation that best maps `src` points to corresponding `dst` points in the least-squares sense.
+    ///
+    /// Uses Umeyama's method without scaling (also known as Kabsch algorithm).
+    /// Returns `None` if point sets are empty or all `src` points coincide.
+    ///
+    /// Panics if point sets have different lengths.
+    pub fn umeyama(src: &[Vector<T, 3>], dst: &[Vector<T, 3>]) -> Option<Self> {
+        umeyama(src, dst, false)
+            .map(|(_, rot, shift)| Self::new(shift.into(), Rotation3::from_matrix(rot)))
+    }
+}
+
+impl<T> From<Similarity2<T>> for Affine<T, 2>
+where
+    T: Float + NumCast,
+{
+    fn from(sim: Similarity2<T>) -> Self {
+        sim.to_affine()
+    }
+}
+impl<T> From<Similarity3<T>> for Affine<T, 3>
+where
+    T: Float + NumCast,
+{
+    fn from(sim: Similarity3<T>) -> Self {
+        sim.to_affine()
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<T, R, const N: usize> Distribution<Similarity<T, R, N>> for Normal
+where
+    Uniform: Distribution<R>,
+    Normal: Distribution<Shift<T, N>> + Distribution<Scale<T>>,
+{
+    /// Similarity with normally distributed shift and scale and uniformly distributed rotation.
+    fn sample<G: Rng + ?Sized>(&self, rng: &mut G) -> Similarity<T, R, N> {
+        Similarity::new(self.sample(rng), self.sample(rng), rng.sample(&Uniform))
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T, R, const N: usize> AbsDiffEq for Similarity<T, R, N>
+where
+    R: AbsDiffEq<Epsilon = T>,
+    T: AbsDiffEq<Epsilon = T> + Copy,
+{
+    type Epsilon = T;
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        abs_diff_eq!(self.shift, other.shift, epsilon = epsilon)
+            && abs_diff_eq!(self.scale, other.scale, epsilon = epsilon)
+            && abs_diff_eq!(self.rotation, other.rotation, epsilon = epsilon)
+    }
+}
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::vector::*;
+    use approx::assert_abs_diff_eq;
+    use rand_::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+    const EPS: f64 = 1e-12;
+
+    macro_rules! base_tests {
+        ($X:ident, $R:ident, $V:ident, $seed:expr) => {
+            use super::*;
+
+            #[test]
+            fn mapping() {
+                let mut rng = XorShiftRng::seed_from_u64($seed);
+                for _ in 0..SAMPLE_ATTEMPTS {
+                    let a: $X<f64> = rng.sample(&Normal);
+                    let (x, y): ($V<f64>, $V<f64>) = (rng.sample(&Normal), rng.sample(&Normal));
+                    assert_abs_diff_eq!(
+                        (a.apply(x) - a.apply(y)).length(),
+                        (x - y).length() * a.scale().into_mag().abs(),
+                        epsilon = EPS
+                    );
+                    assert_abs_diff_eq!(a.to_affine().apply(x), a.apply(x), epsilon = EPS);
+                    assert_abs_diff_eq!(a.deriv(x, y), a.to_affine().deriv(x, y), epsilon = EPS);
+                }
+            }
+
+            #[test]
+            fn chaining() {
+                let mut rng = XorShiftRng::seed_from_u64($seed + 1);
+                for _ in 0..SAMPLE_ATTEMPTS {
+                    let a: $X<f64> = rng.sample(&Normal);
+                    let b: $X<f64> = rng.sample(&Normal);
+                    let x: $V<f64> = rng.sample(&Normal);
+                    assert_abs_diff_eq!(a.chain(b).apply(x), a.apply(b.apply(x)), epsilon = 1e-9);
+                }
+            }
+
+            #[test]
+            fn inversion() {
+                let mut rng = XorShiftRng::seed_from_u64($seed + 2);
+                for _ in 0..SAMPLE_ATTEMPTS {
+                    let a: $X<f64> = rng.sample(&Normal);
+                    let x: $V<f64> = rng.sample(&Normal);
+                    assert_abs_diff_eq!(
+                        a.inv().apply(a.apply(x)),
+                        x,
+                        epsilon = 1e-9 * x.length().max(1.0)
+                    );
+                    assert_abs_diff_eq!(a.chain(a.inv()).apply(x), x, epsilon = 1e-9);
+                }
+            }
+
+            #[test]
+            fn reordering() {
+                let mut rng = XorShiftRng::seed_from_u64($seed + 3);
+                for _ in 0..SAMPLE_ATTEMPTS {
+                    let a: $X<f64> = rng.sample(&Normal);
+                    let s = Shift::from(rng.sample::<$V<f64>, _>(&Normal));
+                    let k = Scale::from_mag(rng.sample::<f64, _>(&Normal));
+                    let x: $V<f64> = rng.sample(&Normal);
+
+                    let (s_, a_) = a.reorder(s);
+                    assert_abs_diff_eq!(a.apply(s.apply(x)), s_.apply(a_.apply(x)), epsilon = EPS);
+                    let (a_, s_) = s.reorder(a);
+                    assert_abs_diff_eq!(s.apply(a.apply(x)), a_.apply(s_.apply(x)), epsilon = 1e-9);
+                    let (k_, a_) = a.reorder(k);
+                    assert_abs_diff_eq!(a.apply(k.apply(x)), k_.apply(a_.apply(x)), epsilon = 1e-9);
+                    let (a_, k_) = k.reorder(a);
+                    assert_abs_diff_eq!(k.apply(a.apply(x)), a_.apply(k_.apply(x)), epsilon = EPS);
+                }
+            }
+
+            #[test]
+            fn umeyama() {
+                let mut rng = XorShiftRng::seed_from_u64($seed + 4);
+                for _ in 0..SAMPLE_ATTEMPTS {
+                    let a = $X::new(
+                        rng.sample::<Shift<f64, _>, _>(&Normal),
+                        Scale::from_mag(rng.sample::<f64, _>(&Normal).abs() + 0.1),
+                        rng.sample(&Uniform),
+                    );
+                    let mut src = [$V::zero(); 8];
+                    for x in src.iter_mut() {
+                        *x = rng.sample(&Normal);
+                    }
+                    let dst = src.map(|x| a.apply(x));
+                    let b = $X::umeyama(&src, &dst).unwrap();
+                    for x in src {
+                        assert_abs_diff_eq!(b.apply(x), a.apply(x), epsilon = 1e-9);
+                    }
+                    assert_abs_diff_eq!(b.scale(), a.scale(), epsilon = 1e-9);
+
+                    let c = Isometry::new(a.shift(), a.rotation());
+                    let dst = src.map(|x| c.apply(x));
+                    let d = Isometry::<_, $R<f64>, _>::umeyama(&src, &dst).unwrap();
+                    for x in src {
+                        assert_abs_diff_eq!(d.apply(x), c.apply(x), epsilon = 1e-9);
+                    }
+                }
+                assert!($X::<f64>::umeyama(&[], &[]).is_none());
+                assert!($X::umeyama(&[$V::fill(1.0); 4], &[$V::zero(); 4]).is_none());
+            }
+
+            #[test]
+            fn umeyama_reflection() {
+                // Reflected point set is fitted with proper rotation.
+                let p = $V::indices().map(|i| (i + 1) as f64);
+                let src = [$V::zero(), p, p * 2.0, $V::fill(-1.0), $V::fill(0.5)];
+                let dst = src.map(|mut x| {
+                    x[0] = -x[0];
+                    x
+                });
+                let b = $X::umeyama(&src, &dst).unwrap();
+                let m = b.rotation().to_linear().into_matrix();
+                assert_abs_diff_eq!(m.det(), 1.0, epsilon = EPS);
+                assert!(b.scale().into_mag() > 0.0);
+            }
+        };
+    }
+
+    mod s2d {
+        base_tests!(Similarity2, Rotation2, Vector2, 0x170);
+    }
+
+    mod s3d {
+        base_tests!(Similarity3, Rotation3, Vector3, 0x180);
+
+        #[test]
+        fn noisy_registration() {
+            let mut rng = XorShiftRng::seed_from_u64(0x190);
+            let a = Similarity3::new(
+                Vector3::from([1.0, -2.0, 0.5]).into(),
+                Scale::from_mag(1.5),
+                Rotation3::new(Vector3::from([1.0, 1.0, 0.0]).normalize(), 0.7),
+            );
+            let mut src = [Vector3::zero(); 64];
+            let mut dst = [Vector3::zero(); 64];
+            for (x, y) in src.iter_mut().zip(dst.iter_mut()) {
+                *x = rng.sample(&Normal);
+                *y = a.apply(*x) + rng.sample::<Vector3<f64>, _>(&Normal) * 1e-4;
+            }
+            let b = Similarity3::umeyama(&src, &dst).unwrap();
+            assert_abs_diff_eq!(b.scale(), a.scale(), epsilon = 1e-3);
+            assert_abs_diff_eq!(b.shift(), a.shift(), epsilon = 1e-3);
+            let x = Vector3::from([0.3, 0.2, -0.1]);
+            assert_abs_diff_eq!(b.apply(x), a.apply(x), epsilon = 1e-3);
+        }
+    }
+}
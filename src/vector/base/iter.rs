@@ -104,7 +104,7 @@ impl<T, const N: usize> Vector<T, N> {
     }
     /// Zip two vectors into one.
     pub fn zip<U>(self, other: Vector<U, N>) -> Vector<(T, U), N> {
-        Vector::try_from_iter(&mut self.into_iter().zip(other.into_iter())).unwrap()
+        Vector::try_from_iter(&mut self.into_iter().zip(other)).unwrap()
     }
     /// Enumerate vector elements.
     pub fn enumerate(self) -> Vector<(usize, T), N> {
@@ -1,11 +1,13 @@
 mod format;
 mod init;
 mod iter;
+mod slice;
 
 #[cfg(test)]
 mod tests;
 
 pub use iter::*;
+pub use slice::*;
 
 /// Vector of fixed size.
 #[repr(transparent)]
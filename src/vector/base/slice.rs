@@ -0,0 +1,59 @@
+use super::Vector;
+use core::{fmt, slice};
+
+/// Error returned when the length of a flat slice is not a multiple of the number of elements in the item.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SliceLengthError {
+    /// Length of the flat slice.
+    pub len: usize,
+    /// Number of scalar elements in a single item.
+    pub size: usize,
+}
+
+impl fmt::Display for SliceLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "slice of length {} cannot be split into items of {} elements",
+            self.len, self.size
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SliceLengthError {}
+
+/// Number of items of `size` elements in flat slice of length `len`.
+pub(crate) fn flat_count(len: usize, size: usize) -> Result<usize, SliceLengthError> {
+    match len.checked_div(size) {
+        Some(count) if count * size == len => Ok(count),
+        None if len == 0 => Ok(0),
+        _ => Err(SliceLengthError { len, size }),
+    }
+}
+
+impl<T, const N: usize> Vector<T, N> {
+    /// Reinterpret flat slice of elements as slice of vectors without copying.
+    ///
+    /// Slice length must be a multiple of `N`, otherwise `Err` is returned.
+    pub fn slice_from_flat(s: &[T]) -> Result<&[Self], SliceLengthError> {
+        let count = flat_count(s.len(), N)?;
+        // Vector is `repr(transparent)` over `[T; N]`, so its layout is the same as `N` consecutive elements.
+        Ok(unsafe { slice::from_raw_parts(s.as_ptr() as *const Self, count) })
+    }
+    /// Reinterpret flat mutable slice of elements as mutable slice of vectors without copying.
+    ///
+    /// Slice length must be a multiple of `N`, otherwise `Err` is returned.
+    pub fn slice_from_flat_mut(s: &mut [T]) -> Result<&mut [Self], SliceLengthError> {
+        let count = flat_count(s.len(), N)?;
+        Ok(unsafe { slice::from_raw_parts_mut(s.as_mut_ptr() as *mut Self, count) })
+    }
+    /// Reinterpret slice of vectors as flat slice of their elements without copying.
+    pub fn flatten(s: &[Self]) -> &[T] {
+        unsafe { slice::from_raw_parts(s.as_ptr() as *const T, s.len() * N) }
+    }
+    /// Reinterpret mutable slice of vectors as flat mutable slice of their elements without copying.
+    pub fn flatten_mut(s: &mut [Self]) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(s.as_mut_ptr() as *mut T, s.len() * N) }
+    }
+}
@@ -140,3 +140,39 @@ mod iter {
         assert_eq!(a.into_iter().sum::<usize>(), (15 * 16) / 2);
     }
 }
+
+mod slice {
+    use super::*;
+
+    #[test]
+    fn from_flat() {
+        let a = [0, 1, 2, 3, 4, 5];
+        let vs = <Vector3<i32>>::slice_from_flat(&a).unwrap();
+        assert_eq!(vs, &[Vector::from([0, 1, 2]), Vector::from([3, 4, 5])]);
+        assert_eq!(vs.as_ptr() as *const i32, a.as_ptr());
+        assert_eq!(<Vector3<i32>>::flatten(vs), &a);
+    }
+
+    #[test]
+    fn from_flat_mut() {
+        let mut a = [0; 8];
+        for v in <Vector4<i32>>::slice_from_flat_mut(&mut a).unwrap() {
+            *v += Vector::from([1, 2, 3, 4]);
+        }
+        assert_eq!(a, [1, 2, 3, 4, 1, 2, 3, 4]);
+        let mut vs = [Vector::from([1, 2]); 2];
+        <Vector2<i32>>::flatten_mut(&mut vs)[3] = 5;
+        assert_eq!(vs[1], Vector::from([1, 5]));
+    }
+
+    #[test]
+    fn wrong_length() {
+        assert_eq!(
+            <Vector3<i32>>::slice_from_flat(&[0; 7]),
+            Err(SliceLengthError { len: 7, size: 3 })
+        );
+        assert!(<Vector3<i32>>::slice_from_flat(&[]).unwrap().is_empty());
+        assert!(<Vector<i32, 0>>::slice_from_flat(&[]).unwrap().is_empty());
+        assert!(<Vector<i32, 0>>::slice_from_flat(&[0]).is_err());
+    }
+}
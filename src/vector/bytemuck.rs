@@ -0,0 +1,6 @@
+use crate::Vector;
+use bytemuck::{Pod, Zeroable};
+
+// Vector is `#[repr(transparent)]` over `[T; N]`, so it has no padding.
+unsafe impl<T: Zeroable, const N: usize> Zeroable for Vector<T, N> {}
+unsafe impl<T: Pod, const N: usize> Pod for Vector<T, N> {}
@@ -34,7 +34,7 @@ where
     Normal: Distribution<T>,
 {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector<T, N> {
-        rng.sample(&VectorDistribution::new(self))
+        rng.sample(VectorDistribution::new(self))
     }
 }
 
@@ -1,4 +1,7 @@
-use crate::{traits::{Dot, Normalize}, Vector};
+use crate::{
+    traits::{Dot, Normalize},
+    Vector,
+};
 use core::ops::{Add, Mul};
 use num_traits::Float;
 
@@ -35,7 +38,7 @@ where
 
 impl<T, const N: usize> Normalize for Vector<T, N>
 where
-    T: Float
+    T: Float,
 {
     fn normalize(self) -> Self {
         Vector::normalize(self)
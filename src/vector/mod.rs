@@ -1,14 +1,20 @@
 #[cfg(feature = "approx")]
 mod approx;
 mod base;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
 #[cfg(feature = "rand")]
 mod distr;
 mod dot;
+mod math;
 mod ops;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "simd")]
+mod simd;
 mod spec;
 #[cfg(test)]
 mod tests;
-mod math;
 
 pub use base::*;
 #[cfg(feature = "rand")]
@@ -44,9 +44,7 @@ where
     T: BitAndAssign,
 {
     fn bitand_assign(&mut self, other: Vector<T, N>) {
-        self.iter_mut()
-            .zip(other.into_iter())
-            .for_each(|(s, y)| *s &= y)
+        self.iter_mut().zip(other).for_each(|(s, y)| *s &= y)
     }
 }
 impl<T, const N: usize> BitOrAssign for Vector<T, N>
@@ -54,9 +52,7 @@ where
     T: BitOrAssign,
 {
     fn bitor_assign(&mut self, other: Vector<T, N>) {
-        self.iter_mut()
-            .zip(other.into_iter())
-            .for_each(|(s, y)| *s |= y)
+        self.iter_mut().zip(other).for_each(|(s, y)| *s |= y)
     }
 }
 impl<T, const N: usize> BitXorAssign for Vector<T, N>
@@ -64,9 +60,7 @@ where
     T: BitXorAssign,
 {
     fn bitxor_assign(&mut self, other: Vector<T, N>) {
-        self.iter_mut()
-            .zip(other.into_iter())
-            .for_each(|(s, y)| *s ^= y)
+        self.iter_mut().zip(other).for_each(|(s, y)| *s ^= y)
     }
 }
 
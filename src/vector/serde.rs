@@ -0,0 +1,88 @@
+use crate::Vector;
+use core::{fmt, iter, marker::PhantomData};
+use serde::{
+    de::{Error, SeqAccess, Visitor},
+    ser::SerializeTuple,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// Vector is serialized as a fixed-length sequence of its elements.
+impl<T: Serialize, const N: usize> Serialize for Vector<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for x in self.iter() {
+            tuple.serialize_element(x)?;
+        }
+        tuple.end()
+    }
+}
+
+struct VectorVisitor<T, const N: usize> {
+    phantom: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for VectorVisitor<T, N> {
+    type Value = Vector<T, N>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a sequence of {} elements", N)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let (mut count, mut error) = (0, None);
+        let vec = Vector::try_from_iter(
+            iter::from_fn(|| match seq.next_element() {
+                Ok(x) => {
+                    count += x.is_some() as usize;
+                    x
+                }
+                Err(e) => {
+                    error = Some(e);
+                    None
+                }
+            })
+            .take(N),
+        );
+        if let Some(e) = error {
+            return Err(e);
+        }
+        match vec {
+            Some(vec) => match seq.next_element::<T>()? {
+                None => Ok(vec),
+                Some(_) => Err(A::Error::invalid_length(N + 1, &self)),
+            },
+            None => Err(A::Error::invalid_length(count, &self)),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for Vector<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_tuple(
+            N,
+            VectorVisitor {
+                phantom: PhantomData,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Vector;
+
+    #[test]
+    fn round_trip() {
+        let v = Vector::from([1.5f64, -0.1, 1e300]);
+        let s = serde_json::to_string(&v).unwrap();
+        assert_eq!(s, "[1.5,-0.1,1e+300]");
+        assert_eq!(serde_json::from_str::<Vector<f64, 3>>(&s).unwrap(), v);
+    }
+
+    #[test]
+    fn wrong_length() {
+        assert!(serde_json::from_str::<Vector<i32, 3>>("[1,2]").is_err());
+        assert!(serde_json::from_str::<Vector<i32, 3>>("[1,2,3,4]").is_err());
+        assert!(serde_json::from_str::<Vector<i32, 0>>("[]").is_ok());
+    }
+}
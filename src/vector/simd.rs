@@ -0,0 +1,106 @@
+//! Explicit SIMD fast paths for vectors which fit into a single SIMD register.
+//!
+//! Generic operators cannot be specialized for concrete types,
+//! so fast paths are provided as separate `simd_*` methods.
+
+use crate::Vector;
+use wide::{f32x4, f64x2, f64x4};
+
+macro_rules! impl_simd {
+    ($T:ident, $N:expr, $S:ident) => {
+        impl From<Vector<$T, $N>> for $S {
+            #[inline]
+            fn from(vec: Vector<$T, $N>) -> Self {
+                $S::new(vec.into_array())
+            }
+        }
+        impl From<$S> for Vector<$T, $N> {
+            #[inline]
+            fn from(simd: $S) -> Self {
+                Vector::from_array(simd.to_array())
+            }
+        }
+
+        impl Vector<$T, $N> {
+            /// Convert vector into SIMD register.
+            #[inline]
+            pub fn into_simd(self) -> $S {
+                self.into()
+            }
+            /// Convert SIMD register into vector.
+            #[inline]
+            pub fn from_simd(simd: $S) -> Self {
+                simd.into()
+            }
+
+            /// Component-wise sum using SIMD instructions.
+            #[inline]
+            pub fn simd_add(self, other: Self) -> Self {
+                (self.into_simd() + other.into_simd()).into()
+            }
+            /// Component-wise difference using SIMD instructions.
+            #[inline]
+            pub fn simd_sub(self, other: Self) -> Self {
+                (self.into_simd() - other.into_simd()).into()
+            }
+            /// Component-wise product using SIMD instructions.
+            #[inline]
+            pub fn simd_mul(self, other: Self) -> Self {
+                (self.into_simd() * other.into_simd()).into()
+            }
+            /// Component-wise quotient using SIMD instructions.
+            #[inline]
+            pub fn simd_div(self, other: Self) -> Self {
+                (self.into_simd() / other.into_simd()).into()
+            }
+            /// Product by scalar using SIMD instructions.
+            #[inline]
+            pub fn simd_scale(self, a: $T) -> Self {
+                (self.into_simd() * $S::splat(a)).into()
+            }
+            /// Dot product using SIMD instructions.
+            #[inline]
+            pub fn simd_dot(self, other: Self) -> $T {
+                (self.into_simd() * other.into_simd()).reduce_add()
+            }
+        }
+    };
+}
+
+impl_simd!(f32, 4, f32x4);
+impl_simd!(f64, 2, f64x2);
+impl_simd!(f64, 4, f64x4);
+
+#[cfg(all(test, feature = "approx", feature = "rand"))]
+mod tests {
+    use crate::{distr::Normal, prelude::*, Vector};
+    use approx::assert_abs_diff_eq;
+    use rand_::prelude::*;
+    use rand_xorshift::XorShiftRng;
+
+    const SAMPLE_ATTEMPTS: usize = 256;
+
+    macro_rules! test_simd {
+        ($name:ident, $T:ident, $N:expr) => {
+            #[test]
+            fn $name() {
+                let mut rng = XorShiftRng::seed_from_u64(0xBEEF);
+                for _ in 0..SAMPLE_ATTEMPTS {
+                    let a: Vector<$T, $N> = rng.sample(&Normal);
+                    let b: Vector<$T, $N> = rng.sample(&Normal);
+                    let c: $T = rng.sample(&Normal);
+                    assert_eq!(a.simd_add(b), a + b);
+                    assert_eq!(a.simd_sub(b), a - b);
+                    assert_eq!(a.simd_mul(b), a * b);
+                    assert_eq!(a.simd_div(b), a / b);
+                    assert_eq!(a.simd_scale(c), a * c);
+                    assert_abs_diff_eq!(a.simd_dot(b), a.dot(b), epsilon = 1e-6);
+                }
+            }
+        };
+    }
+
+    test_simd!(f32x4, f32, 4);
+    test_simd!(f64x2, f64, 2);
+    test_simd!(f64x4, f64, 4);
+}